@@ -0,0 +1,281 @@
+// Copyright 2019 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Axis-aligned bounding boxes, described by their minimum and maximum
+//! corners.
+
+use num_traits::cast;
+
+use structure::*;
+
+use approx;
+use num::BaseFloat;
+use point::{Point2, Point3};
+use ray::{Ray2, Ray3};
+use transform::Transform;
+use vector::{Vector2, Vector3};
+
+/// An axis-aligned bounding box in 2-dimensional space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Aabb2<S> {
+    /// The corner with the smallest coordinates.
+    pub min: Point2<S>,
+    /// The corner with the largest coordinates.
+    pub max: Point2<S>,
+}
+
+/// An axis-aligned bounding box in 3-dimensional space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Aabb3<S> {
+    /// The corner with the smallest coordinates.
+    pub min: Point3<S>,
+    /// The corner with the largest coordinates.
+    pub max: Point3<S>,
+}
+
+macro_rules! impl_aabb {
+    ($AabbN:ident, $PointN:ident, $VectorN:ident, $RayN:ident, $n:expr) => {
+        impl<S: BaseFloat> $AabbN<S> {
+            /// Construct a new box from two opposite corners, in any order.
+            #[inline]
+            pub fn new(a: $PointN<S>, b: $PointN<S>) -> $AabbN<S> {
+                $AabbN {
+                    min: a.zip(b, S::min),
+                    max: a.zip(b, S::max),
+                }
+            }
+
+            /// Construct the smallest box containing all of the points in the
+            /// slice, returning `None` if the slice is empty.
+            pub fn from_points(points: &[$PointN<S>]) -> Option<$AabbN<S>> {
+                let (first, rest) = match points.split_first() {
+                    Some(split) => split,
+                    None => return None,
+                };
+                let init = $AabbN {
+                    min: *first,
+                    max: *first,
+                };
+                Some(rest.iter().fold(init, |aabb, &p| aabb.grow(p)))
+            }
+
+            /// Returns the smallest box containing both this box and `point`.
+            #[inline]
+            pub fn grow(&self, point: $PointN<S>) -> $AabbN<S> {
+                $AabbN {
+                    min: self.min.zip(point, S::min),
+                    max: self.max.zip(point, S::max),
+                }
+            }
+
+            /// Returns the smallest box containing both this box and `other`.
+            #[inline]
+            pub fn union(&self, other: &$AabbN<S>) -> $AabbN<S> {
+                $AabbN {
+                    min: self.min.zip(other.min, S::min),
+                    max: self.max.zip(other.max, S::max),
+                }
+            }
+
+            /// Returns the box shared by this box and `other`, or `None` if
+            /// they do not overlap.
+            pub fn intersection(&self, other: &$AabbN<S>) -> Option<$AabbN<S>> {
+                let min = self.min.zip(other.min, S::max);
+                let max = self.max.zip(other.max, S::min);
+                for i in 0..$n {
+                    if min[i] > max[i] {
+                        return None;
+                    }
+                }
+                Some($AabbN { min: min, max: max })
+            }
+
+            /// Returns `true` if this box and `other` overlap. Boxes that only
+            /// touch are considered to be overlapping.
+            #[inline]
+            pub fn intersects(&self, other: &$AabbN<S>) -> bool {
+                self.intersection(other).is_some()
+            }
+
+            /// Returns `true` if the point lies inside the box or on its
+            /// boundary.
+            pub fn contains_point(&self, point: $PointN<S>) -> bool {
+                for i in 0..$n {
+                    if point[i] < self.min[i] || point[i] > self.max[i] {
+                        return false;
+                    }
+                }
+                true
+            }
+
+            /// Returns `true` if `other` lies entirely inside this box.
+            #[inline]
+            pub fn contains(&self, other: &$AabbN<S>) -> bool {
+                self.contains_point(other.min) && self.contains_point(other.max)
+            }
+
+            /// The point halfway between the two corners.
+            #[inline]
+            pub fn center(&self) -> $PointN<S> {
+                self.min.midpoint(self.max)
+            }
+
+            /// The size of the box along each axis, that is `max - min`.
+            #[inline]
+            pub fn extents(&self) -> $VectorN<S> {
+                self.max - self.min
+            }
+
+            /// Returns the point inside the box that is closest to `point`.
+            #[inline]
+            pub fn closest_point(&self, point: $PointN<S>) -> $PointN<S> {
+                point.zip(self.min, S::max).zip(self.max, S::min)
+            }
+
+            /// Returns a conservative bounding box of this box after it has
+            /// been transformed by an affine `transform`, using
+            /// [Arvo's method](http://www.realtimerendering.com/resources/GraphicsGems/gems/TransBox.c).
+            ///
+            /// The result tightly bounds the transformed corners, so
+            /// when the transformation contains a rotation it may be larger
+            /// than a box fitted to the transformed contents directly.
+            pub fn transform<T: Transform<$PointN<S>>>(&self, transform: &T) -> $AabbN<S> {
+                let mut min = transform.transform_point($PointN::origin());
+                let mut max = min;
+                for j in 0..$n {
+                    let mut unit = $VectorN::zero();
+                    unit[j] = S::one();
+                    let axis = transform.transform_vector(unit);
+                    for i in 0..$n {
+                        let a = axis[i] * self.min[j];
+                        let b = axis[i] * self.max[j];
+                        min[i] += a.min(b);
+                        max[i] += a.max(b);
+                    }
+                }
+                $AabbN { min: min, max: max }
+            }
+
+            /// Intersect a ray with the box, returning the distance to the
+            /// first hit and the point that was hit.
+            ///
+            /// See `intersect_aabb` on the ray type for details.
+            #[inline]
+            pub fn intersect_ray(&self, ray: &$RayN<S>) -> Option<(S, $PointN<S>)> {
+                ray.intersect_aabb(self.min, self.max)
+            }
+        }
+
+        impl<S: BaseFloat> approx::AbsDiffEq for $AabbN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                $PointN::abs_diff_eq(&self.min, &other.min, epsilon)
+                    && $PointN::abs_diff_eq(&self.max, &other.max, epsilon)
+            }
+        }
+
+        impl<S: BaseFloat> approx::RelativeEq for $AabbN<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: S::Epsilon,
+                max_relative: S::Epsilon,
+            ) -> bool {
+                $PointN::relative_eq(&self.min, &other.min, epsilon, max_relative)
+                    && $PointN::relative_eq(&self.max, &other.max, epsilon, max_relative)
+            }
+        }
+
+        impl<S: BaseFloat> approx::UlpsEq for $AabbN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                $PointN::ulps_eq(&self.min, &other.min, epsilon, max_ulps)
+                    && $PointN::ulps_eq(&self.max, &other.max, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+impl_aabb!(Aabb2, Point2, Vector2, Ray2, 2);
+impl_aabb!(Aabb3, Point3, Vector3, Ray3, 3);
+
+impl<S: BaseFloat> Aabb2<S> {
+    /// The area enclosed by the box.
+    #[inline]
+    pub fn volume(&self) -> S {
+        self.extents().product()
+    }
+
+    /// The perimeter of the box.
+    #[inline]
+    pub fn surface_area(&self) -> S {
+        let two: S = cast(2).unwrap();
+        self.extents().sum() * two
+    }
+}
+
+impl<S: BaseFloat> Aabb3<S> {
+    /// The volume enclosed by the box.
+    #[inline]
+    pub fn volume(&self) -> S {
+        self.extents().product()
+    }
+
+    /// The total area of the six faces of the box.
+    #[inline]
+    pub fn surface_area(&self) -> S {
+        let two: S = cast(2).unwrap();
+        let e = self.extents();
+        (e.x * e.y + e.y * e.z + e.z * e.x) * two
+    }
+
+    /// The eight corners of the box.
+    ///
+    /// Corner `i` takes its `x`, `y` and `z` coordinates from `max` if bit
+    /// `0`, `1` or `2` of `i` is set respectively, and from `min` otherwise.
+    pub fn corners(&self) -> [Point3<S>; 8] {
+        let (a, b) = (self.min, self.max);
+        [
+            Point3::new(a.x, a.y, a.z),
+            Point3::new(b.x, a.y, a.z),
+            Point3::new(a.x, b.y, a.z),
+            Point3::new(b.x, b.y, a.z),
+            Point3::new(a.x, a.y, b.z),
+            Point3::new(b.x, a.y, b.z),
+            Point3::new(a.x, b.y, b.z),
+            Point3::new(b.x, b.y, b.z),
+        ]
+    }
+}
@@ -0,0 +1,345 @@
+// Copyright 2019 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Probability distributions over geometric objects, for use with the `rand`
+//! crate.
+//!
+//! Unlike the `Standard` distribution, which fills in each component
+//! independently, these take the geometry of the sampled objects into
+//! account.
+
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use num_traits::cast;
+
+use structure::*;
+
+use angle::Rad;
+use matrix::Matrix3;
+use num::BaseFloat;
+use point::{Point2, Point3};
+use quaternion::Quaternion;
+use rotation::Basis3;
+use vector::{Vector2, Vector3};
+
+/// A distribution of rotations that is uniform over all orientations.
+///
+/// Samples unit quaternions with the method described by Ken Shoemake in
+/// _Uniform Random Rotations_ (Graphics Gems III), and can also produce
+/// `Basis3` and `Matrix3` rotations.
+///
+/// ```
+/// # extern crate cgmath;
+/// # extern crate rand;
+/// use cgmath::{Quaternion, UniformRotation};
+/// use rand::Rng;
+///
+/// let q: Quaternion<f64> = rand::thread_rng().sample(UniformRotation);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UniformRotation;
+
+impl<S: BaseFloat> Distribution<Quaternion<S>> for UniformRotation
+where
+    Standard: Distribution<S>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quaternion<S> {
+        let u1: S = rng.gen();
+        let (s2, c2) = Rad::sin_cos(Rad::full_turn() * rng.gen::<S>());
+        let (s3, c3) = Rad::sin_cos(Rad::full_turn() * rng.gen::<S>());
+        let r1 = (S::one() - u1).sqrt();
+        let r2 = u1.sqrt();
+        Quaternion::new(r2 * c3, r1 * s2, r1 * c2, r2 * s3)
+    }
+}
+
+impl<S: BaseFloat> Distribution<Basis3<S>> for UniformRotation
+where
+    Standard: Distribution<S>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Basis3<S> {
+        let q: Quaternion<S> = self.sample(rng);
+        Basis3::from_quaternion(&q)
+    }
+}
+
+impl<S: BaseFloat> Distribution<Matrix3<S>> for UniformRotation
+where
+    Standard: Distribution<S>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Matrix3<S> {
+        let q: Quaternion<S> = self.sample(rng);
+        q.into()
+    }
+}
+
+/// Implement sampling points from a distribution that samples vectors, by
+/// offsetting the vectors from the origin.
+macro_rules! impl_point_distribution {
+    ($Distribution:ty, $PointN:ident, $VectorN:ident) => {
+        impl<S: BaseFloat> Distribution<$PointN<S>> for $Distribution
+        where
+            Standard: Distribution<S>,
+        {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $PointN<S> {
+                let v: $VectorN<S> = self.sample(rng);
+                $PointN::from_vec(v)
+            }
+        }
+    };
+}
+
+/// A point on the unit circle at a uniformly distributed angle.
+fn unit_circle<S: BaseFloat, R: Rng + ?Sized>(rng: &mut R) -> Vector2<S>
+where
+    Standard: Distribution<S>,
+{
+    let (sin, cos) = Rad::sin_cos(Rad::full_turn() * rng.gen::<S>());
+    Vector2::new(cos, sin)
+}
+
+/// A direction in the spherical cap around the `z` axis that contains the
+/// directions with a `z` component of at least `min_z`.
+fn spherical_cap<S: BaseFloat, R: Rng + ?Sized>(rng: &mut R, min_z: S) -> Vector3<S>
+where
+    Standard: Distribution<S>,
+{
+    let z = S::one() - rng.gen::<S>() * (S::one() - min_z);
+    let r = (S::one() - z * z).max(S::zero()).sqrt();
+    (unit_circle(rng) * r).extend(z)
+}
+
+/// A distribution of unit vectors that is uniform over the surface of the
+/// unit sphere.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnitSphere;
+
+impl UnitSphere {
+    /// The probability density of sampling `direction`, with respect to solid
+    /// angle.
+    #[inline]
+    pub fn pdf<S: BaseFloat>(&self, _direction: Vector3<S>) -> S {
+        let four: S = cast(4).unwrap();
+        (four * Rad::<S>::turn_div_2().0).recip()
+    }
+}
+
+impl<S: BaseFloat> Distribution<Vector3<S>> for UnitSphere
+where
+    Standard: Distribution<S>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3<S> {
+        spherical_cap(rng, -S::one())
+    }
+}
+
+impl_point_distribution!(UnitSphere, Point3, Vector3);
+
+/// A distribution of vectors that is uniform over the volume of the unit
+/// ball.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnitBall;
+
+impl UnitBall {
+    /// The probability density of sampling `point`, with respect to volume.
+    #[inline]
+    pub fn pdf<S: BaseFloat>(&self, point: Vector3<S>) -> S {
+        if point.magnitude2() <= S::one() {
+            let three: S = cast(3).unwrap();
+            let four: S = cast(4).unwrap();
+            three / (four * Rad::<S>::turn_div_2().0)
+        } else {
+            S::zero()
+        }
+    }
+}
+
+impl<S: BaseFloat> Distribution<Vector3<S>> for UnitBall
+where
+    Standard: Distribution<S>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3<S> {
+        let direction: Vector3<S> = UnitSphere.sample(rng);
+        direction * rng.gen::<S>().cbrt()
+    }
+}
+
+impl_point_distribution!(UnitBall, Point3, Vector3);
+
+/// A distribution of vectors that is uniform over the area of the unit disk.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnitDisk;
+
+impl UnitDisk {
+    /// The probability density of sampling `point`, with respect to area.
+    #[inline]
+    pub fn pdf<S: BaseFloat>(&self, point: Vector2<S>) -> S {
+        if point.magnitude2() <= S::one() {
+            Rad::<S>::turn_div_2().0.recip()
+        } else {
+            S::zero()
+        }
+    }
+}
+
+impl<S: BaseFloat> Distribution<Vector2<S>> for UnitDisk
+where
+    Standard: Distribution<S>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2<S> {
+        unit_circle(rng) * rng.gen::<S>().sqrt()
+    }
+}
+
+impl_point_distribution!(UnitDisk, Point2, Vector2);
+
+/// A distribution of unit vectors that is uniform over the hemisphere around
+/// the positive `z` axis.
+///
+/// Samples for another hemisphere can be found by rotating them, for example
+/// with `Quaternion::between_vectors(Vector3::unit_z(), normal)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnitHemisphere;
+
+impl UnitHemisphere {
+    /// The probability density of sampling `direction`, with respect to solid
+    /// angle.
+    #[inline]
+    pub fn pdf<S: BaseFloat>(&self, direction: Vector3<S>) -> S {
+        if direction.z >= S::zero() {
+            Rad::<S>::full_turn().0.recip()
+        } else {
+            S::zero()
+        }
+    }
+}
+
+impl<S: BaseFloat> Distribution<Vector3<S>> for UnitHemisphere
+where
+    Standard: Distribution<S>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3<S> {
+        spherical_cap(rng, S::zero())
+    }
+}
+
+impl_point_distribution!(UnitHemisphere, Point3, Vector3);
+
+/// A distribution of unit vectors over the hemisphere around the positive
+/// `z` axis, with a density proportional to the cosine of the angle to the
+/// axis.
+///
+/// This is the ideal distribution for importance sampling diffuse
+/// reflections off a surface with a normal along `z`. The vectors are found
+/// by projecting uniform samples of the unit disk up onto the hemisphere,
+/// using Malley's method.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CosineHemisphere;
+
+impl CosineHemisphere {
+    /// The probability density of sampling `direction`, with respect to solid
+    /// angle.
+    #[inline]
+    pub fn pdf<S: BaseFloat>(&self, direction: Vector3<S>) -> S {
+        direction.z.max(S::zero()) / Rad::<S>::turn_div_2().0
+    }
+}
+
+impl<S: BaseFloat> Distribution<Vector3<S>> for CosineHemisphere
+where
+    Standard: Distribution<S>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3<S> {
+        let v: Vector2<S> = UnitDisk.sample(rng);
+        let z = (S::one() - v.magnitude2()).max(S::zero()).sqrt();
+        v.extend(z)
+    }
+}
+
+impl_point_distribution!(CosineHemisphere, Point3, Vector3);
+
+/// A distribution of unit vectors that is uniform over the directions within
+/// a cone around the positive `z` axis.
+///
+/// This is mostly used to sample the directions towards a spherical light,
+/// or to spread out particles.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UnitCone<S> {
+    cos_half_angle: S,
+}
+
+impl<S: BaseFloat> UnitCone<S> {
+    /// Construct a cone from the angle between its axis and its side.
+    ///
+    /// A half angle of zero always samples the positive `z` axis, with an
+    /// infinite `pdf`, and a half turn samples the whole sphere.
+    ///
+    /// # Panics
+    ///
+    /// If the half angle is not between zero and a half turn.
+    #[inline]
+    pub fn new<A: Into<Rad<S>>>(half_angle: A) -> UnitCone<S> {
+        let half_angle = half_angle.into();
+        assert!(
+            half_angle >= Rad::zero() && half_angle <= Rad::turn_div_2(),
+            "The half angle of a cone must be between zero and a half turn, found: {:?}",
+            half_angle
+        );
+        UnitCone {
+            cos_half_angle: Rad::cos(half_angle),
+        }
+    }
+
+    /// The probability density of sampling `direction`, with respect to solid
+    /// angle.
+    #[inline]
+    pub fn pdf(&self, direction: Vector3<S>) -> S {
+        if direction.z >= self.cos_half_angle {
+            (Rad::<S>::full_turn().0 * (S::one() - self.cos_half_angle)).recip()
+        } else {
+            S::zero()
+        }
+    }
+}
+
+impl<S: BaseFloat> Distribution<Vector3<S>> for UnitCone<S>
+where
+    Standard: Distribution<S>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3<S> {
+        spherical_cap(rng, self.cos_half_angle)
+    }
+}
+
+impl<S: BaseFloat> Distribution<Point3<S>> for UnitCone<S>
+where
+    Standard: Distribution<S>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point3<S> {
+        let v: Vector3<S> = self.sample(rng);
+        Point3::from_vec(v)
+    }
+}
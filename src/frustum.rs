@@ -0,0 +1,259 @@
+// Copyright 2019 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use structure::*;
+
+use aabb::Aabb3;
+use matrix::Matrix4;
+use num::BaseFloat;
+use plane::Plane;
+use point::Point3;
+use projection::ClipSpace;
+use vector::{Vector3, Vector4};
+
+/// The result of testing a shape against a bounding volume.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Relation {
+    /// The shape is completely inside the volume.
+    Inside,
+    /// The shape is completely outside the volume.
+    Outside,
+    /// The shape is partially inside the volume. For conservative tests this
+    /// may also be reported for some shapes that are just outside of it.
+    Intersecting,
+}
+
+/// A convex volume bounded by six planes, usually the region of space that
+/// is visible to a camera.
+///
+/// The normals of all the planes face towards the inside of the frustum.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Frustum<S> {
+    pub left: Plane<S>,
+    pub right: Plane<S>,
+    pub bottom: Plane<S>,
+    pub top: Plane<S>,
+    pub near: Plane<S>,
+    pub far: Plane<S>,
+}
+
+impl<S: BaseFloat> Frustum<S> {
+    /// Construct a frustum from its six planes.
+    #[inline]
+    pub fn new(
+        left: Plane<S>,
+        right: Plane<S>,
+        bottom: Plane<S>,
+        top: Plane<S>,
+        near: Plane<S>,
+        far: Plane<S>,
+    ) -> Frustum<S> {
+        Frustum {
+            left: left,
+            right: right,
+            bottom: bottom,
+            top: top,
+            near: near,
+            far: far,
+        }
+    }
+
+    /// Extract the frustum from a projection matrix, using the method
+    /// described by Gribb and Hartmann in [Fast Extraction of Viewing Frustum
+    /// Planes from the World-View-Projection Matrix](http://www.cs.otago.ca/postgrad/ekman/frustum.pdf).
+    ///
+    /// The matrix is expected to map the frustum onto OpenGL's clip volume,
+    /// where `x`, `y` and `z` all range from `-w` to `w`. If `m` is a
+    /// projection matrix the planes are in view space, and if it is a
+    /// combined view-projection matrix they are in world space.
+    pub fn from_matrix4(m: Matrix4<S>) -> Frustum<S> {
+        Frustum::from_matrix4_clip_space(m, ClipSpace::OPENGL)
+    }
+
+    /// Extract the frustum from a projection matrix that maps onto the clip
+    /// volume described by `clip_space`, such as Direct3D's or Vulkan's
+    /// where `z` ranges from `0` to `w`.
+    ///
+    /// The handedness of `clip_space` does not affect the result, as it is
+    /// already part of the matrix.
+    pub fn from_matrix4_clip_space(m: Matrix4<S>, clip_space: ClipSpace) -> Frustum<S> {
+        let (r0, r1, r2, r3) = (m.row(0), m.row(1), m.row(2), m.row(3));
+        let min_depth: S = if clip_space.reverse_z {
+            clip_space.far_depth()
+        } else {
+            clip_space.near_depth()
+        };
+        let lower = plane_from_row(r2 - r3 * min_depth);
+        let upper = plane_from_row(r3 - r2);
+        let (near, far) = if clip_space.reverse_z {
+            (upper, lower)
+        } else {
+            (lower, upper)
+        };
+        Frustum::new(
+            plane_from_row(r3 + r0),
+            plane_from_row(r3 - r0),
+            plane_from_row(r3 + r1),
+            plane_from_row(r3 - r1),
+            near,
+            far,
+        )
+    }
+
+    /// The six planes of the frustum, in the order left, right, bottom, top,
+    /// near and far.
+    #[inline]
+    pub fn planes(&self) -> [Plane<S>; 6] {
+        [
+            self.left,
+            self.right,
+            self.bottom,
+            self.top,
+            self.near,
+            self.far,
+        ]
+    }
+
+    /// The eight corners of the frustum, found by intersecting its planes.
+    ///
+    /// Corner `i` lies on the right plane if bit `0` of `i` is set and on the
+    /// left plane otherwise, on the top or bottom plane depending on bit `1`,
+    /// and on the far or near plane depending on bit `2`. This matches the
+    /// order of `Aabb3::corners`.
+    ///
+    /// If the far plane is at infinity, as for a frustum extracted from an
+    /// `InfinitePerspectiveFov`, it has no finite intersection with the other
+    /// planes. The far corners, `4` to `7`, then have infinite or NaN
+    /// coordinates, and only the near corners are meaningful.
+    pub fn corners(&self) -> [Point3<S>; 8] {
+        let x = [self.left, self.right];
+        let y = [self.bottom, self.top];
+        let z = [self.near, self.far];
+        let corner = |i: usize| intersect_planes(&x[i & 1], &y[(i >> 1) & 1], &z[(i >> 2) & 1]);
+        [
+            corner(0),
+            corner(1),
+            corner(2),
+            corner(3),
+            corner(4),
+            corner(5),
+            corner(6),
+            corner(7),
+        ]
+    }
+
+    /// Returns `true` if the point lies inside the frustum or on its boundary.
+    #[inline]
+    pub fn contains_point(&self, point: Point3<S>) -> bool {
+        self.relate_point(point) != Relation::Outside
+    }
+
+    /// Test a point against the frustum. Points on the boundary of the
+    /// frustum are reported as `Relation::Intersecting`.
+    pub fn relate_point(&self, point: Point3<S>) -> Relation {
+        let mut result = Relation::Inside;
+        for plane in self.planes().iter() {
+            let d = plane.signed_distance(point);
+            if d < S::zero() {
+                return Relation::Outside;
+            } else if d == S::zero() {
+                result = Relation::Intersecting;
+            }
+        }
+        result
+    }
+
+    /// Test the sphere with the given center and radius against the frustum.
+    ///
+    /// Spheres close to the edges of the frustum can be reported as
+    /// intersecting even though they are outside of it.
+    pub fn relate_sphere(&self, center: Point3<S>, radius: S) -> Relation {
+        let mut result = Relation::Inside;
+        for plane in self.planes().iter() {
+            let d = plane.signed_distance(center);
+            if d < -radius {
+                return Relation::Outside;
+            } else if d < radius {
+                result = Relation::Intersecting;
+            }
+        }
+        result
+    }
+
+    /// Test an axis-aligned bounding box against the frustum.
+    ///
+    /// Boxes close to the edges of the frustum can be reported as
+    /// intersecting even though they are outside of it.
+    pub fn relate_aabb(&self, aabb: &Aabb3<S>) -> Relation {
+        let mut result = Relation::Inside;
+        for plane in self.planes().iter() {
+            // the corners of the box furthest along and against the normal
+            let n = plane.normal;
+            let pick = |positive: bool| {
+                Point3::new(
+                    if (n.x >= S::zero()) == positive {
+                        aabb.max.x
+                    } else {
+                        aabb.min.x
+                    },
+                    if (n.y >= S::zero()) == positive {
+                        aabb.max.y
+                    } else {
+                        aabb.min.y
+                    },
+                    if (n.z >= S::zero()) == positive {
+                        aabb.max.z
+                    } else {
+                        aabb.min.z
+                    },
+                )
+            };
+            if plane.signed_distance(pick(true)) < S::zero() {
+                return Relation::Outside;
+            } else if plane.signed_distance(pick(false)) < S::zero() {
+                result = Relation::Intersecting;
+            }
+        }
+        result
+    }
+}
+
+impl<S: BaseFloat> From<Matrix4<S>> for Frustum<S> {
+    #[inline]
+    fn from(m: Matrix4<S>) -> Frustum<S> {
+        Frustum::from_matrix4(m)
+    }
+}
+
+/// Normalize a plane extracted from a matrix row. A plane at infinity, for
+/// example the far plane of an infinite projection, has no normal and is kept
+/// as is, so that every point is in front of it.
+fn plane_from_row<S: BaseFloat>(v: Vector4<S>) -> Plane<S> {
+    if v.truncate().magnitude2() == S::zero() {
+        Plane::new(Vector3::zero(), v.w)
+    } else {
+        Plane::from(v)
+    }
+}
+
+/// The point shared by three planes, using Cramer's rule.
+fn intersect_planes<S: BaseFloat>(a: &Plane<S>, b: &Plane<S>, c: &Plane<S>) -> Point3<S> {
+    let bc = b.normal.cross(c.normal);
+    let ca = c.normal.cross(a.normal);
+    let ab = a.normal.cross(b.normal);
+    let denom = a.normal.dot(bc);
+    Point3::from_vec((bc * a.distance + ca * b.distance + ab * c.distance) / -denom)
+}
@@ -75,7 +75,7 @@ pub use approx::*;
 pub use num::*;
 pub use structure::*;
 
-pub use matrix::{Matrix2, Matrix3, Matrix4};
+pub use matrix::{Matrix2, Matrix3, Matrix4, Orthonormalization};
 pub use quaternion::Quaternion;
 pub use vector::{dot, vec1, vec2, vec3, vec4, Vector1, Vector2, Vector3, Vector4};
 
@@ -87,6 +87,18 @@ pub use transform::*;
 
 pub use projection::*;
 
+pub use aabb::{Aabb2, Aabb3};
+pub use frustum::{Frustum, Relation};
+pub use obb::Obb3;
+pub use plane::Plane;
+pub use ray::{Ray2, Ray3};
+pub use sphere::Sphere;
+
+#[cfg(feature = "rand")]
+pub use distributions::{
+    CosineHemisphere, UniformRotation, UnitBall, UnitCone, UnitDisk, UnitHemisphere, UnitSphere,
+};
+
 // Modules
 
 pub mod conv;
@@ -115,3 +127,13 @@ mod rotation;
 mod transform;
 
 mod projection;
+
+mod aabb;
+mod frustum;
+mod obb;
+mod plane;
+mod ray;
+mod sphere;
+
+#[cfg(feature = "rand")]
+mod distributions;
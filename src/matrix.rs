@@ -31,8 +31,10 @@ use angle::Rad;
 use approx;
 use euler::Euler;
 use num::BaseFloat;
+use plane::Plane;
 use point::{Point2, Point3};
 use quaternion::Quaternion;
+use rotation::{Basis2, Basis3, Rotation2};
 use transform::{Transform, Transform2, Transform3};
 use vector::{Vector2, Vector3, Vector4};
 
@@ -125,6 +127,25 @@ impl<S: BaseFloat> Matrix2<S> {
         Matrix2::new(c, s, -s, c)
     }
 
+    /// Compute the eigenvalues and eigenvectors of a symmetric matrix.
+    ///
+    /// The eigenvalues are returned in decreasing order, and the columns of
+    /// the returned rotation are the matching unit eigenvectors, so that
+    /// `self == R * Matrix2::from_diagonal(values) * R.transpose()`. Only the
+    /// symmetric part of the matrix is used.
+    pub fn symmetric_eigen(&self) -> (Vector2<S>, Basis2<S>) {
+        let half: S = cast(0.5f64).unwrap();
+        let (a, b, c) = (self[0][0], (self[0][1] + self[1][0]) * half, self[1][1]);
+        let mean = (a + c) * half;
+        let radius = ((a - c) * half).hypot(b);
+        // the angle of the eigenvector with the largest eigenvalue
+        let theta = Rad(b.atan2((a - c) * half) * half);
+        (
+            Vector2::new(mean + radius, mean - radius),
+            Basis2::from_angle(theta),
+        )
+    }
+
     /// Are all entries in the matrix finite.
     pub fn is_finite(&self) -> bool {
         self.x.is_finite() && self.y.is_finite()
@@ -259,12 +280,111 @@ impl<S: BaseFloat> Matrix3<S> {
         )
     }
 
+    /// Compute the eigenvalues and eigenvectors of a symmetric matrix, such
+    /// as an inertia tensor or a covariance matrix, using the cyclic
+    /// [Jacobi eigenvalue algorithm](https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm).
+    ///
+    /// The eigenvalues are returned in decreasing order, and the columns of
+    /// the returned rotation are the matching unit eigenvectors, so that
+    /// `self == R * Matrix3::from_diagonal(values) * R.transpose()`. The sign
+    /// of the last eigenvector is chosen to make the basis right-handed. Only
+    /// the symmetric part of the matrix is used.
+    pub fn symmetric_eigen(&self) -> (Vector3<S>, Basis3<S>) {
+        let half: S = cast(0.5f64).unwrap();
+        let mut a = (*self + self.transpose()) * half;
+        let mut v = Matrix3::identity();
+        for _ in 0..32 {
+            let off = a[1][0] * a[1][0] + a[2][0] * a[2][0] + a[2][1] * a[2][1];
+            if off <= a.diagonal().magnitude2() * S::epsilon() * S::epsilon() {
+                break;
+            }
+            for &(p, q) in [(0, 1), (0, 2), (1, 2)].iter() {
+                if a[q][p] == S::zero() {
+                    continue;
+                }
+                // the rotation in the `p`-`q` plane that zeroes `a[q][p]`
+                let theta = (a[q][q] - a[p][p]) * half / a[q][p];
+                let t = theta.signum() / (theta.abs() + theta.hypot(S::one()));
+                let c = S::one() / t.hypot(S::one());
+                let s = t * c;
+
+                let mut rot = Matrix3::identity();
+                rot[p][p] = c;
+                rot[q][q] = c;
+                rot[q][p] = s;
+                rot[p][q] = -s;
+                a = rot.transpose() * a * rot;
+                v = v * rot;
+            }
+        }
+
+        let mut values = a.diagonal();
+        for i in 0..2 {
+            for j in (i + 1..3).rev() {
+                if values[j] > values[j - 1] {
+                    values.swap_elements(j, j - 1);
+                    v.swap_columns(j, j - 1);
+                }
+            }
+        }
+        if v.determinant() < S::zero() {
+            v.z = -v.z;
+        }
+        let q: Quaternion<S> = v.into();
+        (values, Basis3::from_quaternion(&q.normalize()))
+    }
+
+    /// Compute the [polar decomposition](https://en.wikipedia.org/wiki/Polar_decomposition)
+    /// of the matrix, returning `(rotation, stretch)` such that
+    /// `self == rotation * stretch`.
+    ///
+    /// `rotation` is the rotation closest to the matrix, and `stretch` is
+    /// symmetric. If the matrix contains a reflection, `rotation` stays a
+    /// proper rotation and the reflection is left in `stretch`, which then has
+    /// a negative eigenvalue.
+    pub fn polar_decompose(&self) -> (Matrix3<S>, Matrix3<S>) {
+        let (u, sigma, vt) = self.signed_svd();
+        (u * vt, vt.transpose() * Matrix3::from_diagonal(sigma) * vt)
+    }
+
+    /// Returns a matrix with orthonormal columns that is close to this one,
+    /// which is useful for repairing rotation matrices that have drifted
+    /// after many multiplications.
+    ///
+    /// See `Orthonormalization` for the available methods.
+    pub fn orthonormalize(&self, method: Orthonormalization) -> Matrix3<S> {
+        match method {
+            Orthonormalization::GramSchmidt => {
+                let x = self.x.normalize();
+                let y = (self.y - x * x.dot(self.y)).normalize();
+                Matrix3::from_cols(x, y, x.cross(y))
+            }
+            Orthonormalization::NearestRotation => self.polar_decompose().0,
+        }
+    }
+
     /// Are all entries in the matrix finite.
     pub fn is_finite(&self) -> bool {
         self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
     }
 }
 
+/// The methods that `Matrix3::orthonormalize` can use to turn a matrix into
+/// a rotation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Orthonormalization {
+    /// Keep the direction of the `x` column, make the `y` column orthogonal
+    /// to it, and replace the `z` column with their cross product.
+    ///
+    /// This is cheap, but the error is not spread evenly between the axes.
+    GramSchmidt,
+    /// Find the rotation closest to the matrix in the Frobenius norm, using
+    /// its polar decomposition.
+    ///
+    /// This is more expensive, but it treats all axes the same.
+    NearestRotation,
+}
+
 impl<S> Matrix4<S> {
     /// Create a new matrix, providing values for each index.
     #[inline]
@@ -331,6 +451,26 @@ impl<S: BaseFloat> Matrix4<S> {
         )
     }
 
+    /// Create a homogeneous transformation matrix that mirrors points and
+    /// vectors across `plane`.
+    ///
+    /// The normal of the plane must be normalized. Note that the resulting
+    /// matrix flips the handedness of the space, so the winding order of
+    /// triangles drawn with it should be reversed.
+    pub fn from_reflection_plane(plane: Plane<S>) -> Matrix4<S> {
+        let two: S = cast(2).unwrap();
+        let n = plane.normal;
+        let d = plane.distance;
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        Matrix4::new(
+            S::one() - two * n.x * n.x, -two * n.x * n.y, -two * n.x * n.z, S::zero(),
+            -two * n.y * n.x, S::one() - two * n.y * n.y, -two * n.y * n.z, S::zero(),
+            -two * n.z * n.x, -two * n.z * n.y, S::one() - two * n.z * n.z, S::zero(),
+            -two * d * n.x, -two * d * n.y, -two * d * n.z, S::one(),
+        )
+    }
+
     /// Create a homogeneous transformation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
     pub fn look_at_dir(eye: Point3<S>, dir: Vector3<S>, up: Vector3<S>) -> Matrix4<S> {
@@ -1238,6 +1378,127 @@ impl_matrix!(Matrix3, Vector3 { x: 0, y: 1, z: 2 });
 #[cfg_attr(rustfmt, rustfmt_skip)]
 impl_matrix!(Matrix4, Vector4 { x: 0, y: 1, z: 2, w: 3 });
 
+macro_rules! impl_svd {
+    ($MatrixN:ident, $VectorN:ident, $n:expr) => {
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// Compute the [singular value decomposition](https://en.wikipedia.org/wiki/Singular_value_decomposition)
+            /// of the matrix, returning `(u, sigma, vt)` such that
+            /// `self == u * Self::from_diagonal(sigma) * vt`.
+            ///
+            /// `u` and `vt` are orthogonal, and the singular values in `sigma`
+            /// are non-negative and sorted in decreasing order. Either `u` or
+            /// `vt` may contain a reflection, use `signed_svd` if both of them
+            /// need to be rotations.
+            ///
+            /// The decomposition is computed with one-sided Jacobi rotations,
+            /// which find even small singular values to high relative
+            /// accuracy.
+            pub fn svd(&self) -> ($MatrixN<S>, $VectorN<S>, $MatrixN<S>) {
+                let mut a = *self;
+                let mut v = $MatrixN::identity();
+                for _ in 0..32 {
+                    let mut rotated = false;
+                    for p in 0..$n {
+                        for q in p + 1..$n {
+                            let alpha = a[p].magnitude2();
+                            let beta = a[q].magnitude2();
+                            let gamma = a[p].dot(a[q]);
+                            if gamma.abs() <= S::epsilon() * (alpha * beta).sqrt() {
+                                continue;
+                            }
+                            rotated = true;
+
+                            // the rotation that makes columns `p` and `q`
+                            // orthogonal to each other
+                            let zeta = (beta - alpha) / (gamma + gamma);
+                            let t = zeta.signum() / (zeta.abs() + zeta.hypot(S::one()));
+                            let c = S::one() / t.hypot(S::one());
+                            let s = c * t;
+                            let (ap, aq) = (a[p], a[q]);
+                            a[p] = ap * c - aq * s;
+                            a[q] = ap * s + aq * c;
+                            let (vp, vq) = (v[p], v[q]);
+                            v[p] = vp * c - vq * s;
+                            v[q] = vp * s + vq * c;
+                        }
+                    }
+                    if !rotated {
+                        break;
+                    }
+                }
+
+                // the columns are now orthogonal, and their lengths are the
+                // singular values
+                let mut sigma = $VectorN::zero();
+                for i in 0..$n {
+                    sigma[i] = a[i].magnitude();
+                }
+                for i in 0..$n {
+                    let mut max = i;
+                    for j in i + 1..$n {
+                        if sigma[j] > sigma[max] {
+                            max = j;
+                        }
+                    }
+                    if max != i {
+                        sigma.swap_elements(i, max);
+                        a.swap_columns(i, max);
+                        v.swap_columns(i, max);
+                    }
+                }
+
+                let mut u = $MatrixN::zero();
+                for i in 0..$n {
+                    if sigma[i] > sigma[0] * S::epsilon() {
+                        u[i] = a[i] / sigma[i];
+                    } else {
+                        // the column has vanished, so complete the basis with
+                        // the unit axis that is furthest from the other columns
+                        let mut best = $VectorN::zero();
+                        for k in 0..$n {
+                            let mut e = $VectorN::zero();
+                            e[k] = S::one();
+                            for j in 0..i {
+                                e -= u[j] * u[j][k];
+                            }
+                            if e.magnitude2() > best.magnitude2() {
+                                best = e;
+                            }
+                        }
+                        u[i] = best.normalize();
+                    }
+                }
+                (u, sigma, v.transpose())
+            }
+
+            /// Compute the singular value decomposition of the matrix, like
+            /// `svd`, but with both `u` and `vt` being proper rotations.
+            ///
+            /// If the matrix contains a reflection, it is represented by
+            /// negating the smallest singular value, so that all but the last
+            /// value in `sigma` are non-negative.
+            pub fn signed_svd(&self) -> ($MatrixN<S>, $VectorN<S>, $MatrixN<S>) {
+                let (mut u, mut sigma, mut vt) = self.svd();
+                if u.determinant() < S::zero() {
+                    u[$n - 1] = -u[$n - 1];
+                    sigma[$n - 1] = -sigma[$n - 1];
+                }
+                if vt.determinant() < S::zero() {
+                    for i in 0..$n {
+                        vt[i][$n - 1] = -vt[i][$n - 1];
+                    }
+                    sigma[$n - 1] = -sigma[$n - 1];
+                }
+                (u, sigma, vt)
+            }
+        }
+    };
+}
+
+impl_svd!(Matrix2, Vector2, 2);
+impl_svd!(Matrix3, Vector3, 3);
+impl_svd!(Matrix4, Vector4, 4);
+
 macro_rules! impl_mv_operator {
     ($MatrixN:ident, $VectorN:ident { $($field:ident : $row_index:expr),+ }) => {
         impl_operator!(<S: BaseFloat> Mul<$VectorN<S> > for $MatrixN<S> {
@@ -0,0 +1,245 @@
+// Copyright 2019 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_traits::cast;
+
+use structure::*;
+
+use approx;
+use matrix::Matrix3;
+use num::BaseFloat;
+use point::Point3;
+use quaternion::Quaternion;
+use rotation::Rotation3;
+use vector::Vector3;
+
+/// An oriented bounding box in 3-dimensional space.
+///
+/// The box spans `-half_extents` to `half_extents` in its local space, which
+/// is rotated by `rot` and then moved to `center`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Obb3<S, R> {
+    pub center: Point3<S>,
+    pub half_extents: Vector3<S>,
+    pub rot: R,
+}
+
+impl<S, R> Obb3<S, R> {
+    /// Construct a new box from its center, half-extents and orientation.
+    #[inline]
+    pub const fn new(center: Point3<S>, half_extents: Vector3<S>, rot: R) -> Obb3<S, R> {
+        Obb3 {
+            center: center,
+            half_extents: half_extents,
+            rot: rot,
+        }
+    }
+}
+
+impl<S: BaseFloat, R: Rotation3<S>> Obb3<S, R> {
+    /// Fit a box to a point cloud, such as the vertices of a mesh, returning
+    /// `None` if the slice is empty.
+    ///
+    /// The axes of the box are the principal axes of the points, found as the
+    /// eigenvectors of their covariance matrix. This gives good results for
+    /// evenly distributed points, but is sensitive to dense clusters, so
+    /// callers with very irregular meshes may prefer to pass only the vertices
+    /// of the convex hull.
+    pub fn from_points(points: &[Point3<S>]) -> Option<Obb3<S, R>>
+    where
+        R: From<Quaternion<S>>,
+    {
+        if points.is_empty() {
+            return None;
+        }
+        let mean = Point3::centroid(points);
+        let covariance = points.iter().fold(Matrix3::zero(), |acc, &p| {
+            let d = p - mean;
+            acc + Matrix3::from_cols(d * d.x, d * d.y, d * d.z)
+        }) / cast(points.len()).unwrap();
+
+        let (_, axes) = covariance.symmetric_eigen();
+        let rot = R::from(Quaternion::from(axes));
+
+        let inv = rot.invert();
+        let first = inv.rotate_vector(points[0] - mean);
+        let (min, max) = points.iter().fold((first, first), |(min, max), &p| {
+            let local = inv.rotate_vector(p - mean);
+            (min.zip(local, S::min), max.zip(local, S::max))
+        });
+        let half: S = cast(0.5f64).unwrap();
+        Some(Obb3::new(
+            mean + rot.rotate_vector((min + max) * half),
+            (max - min) * half,
+            rot,
+        ))
+    }
+
+    /// The directions of the local `x`, `y` and `z` axes of the box.
+    #[inline]
+    pub fn axes(&self) -> [Vector3<S>; 3] {
+        [
+            self.rot.rotate_vector(Vector3::unit_x()),
+            self.rot.rotate_vector(Vector3::unit_y()),
+            self.rot.rotate_vector(Vector3::unit_z()),
+        ]
+    }
+
+    /// The eight corners of the box, in the same order as `Aabb3::corners`
+    /// when viewed in the local space of the box.
+    pub fn corners(&self) -> [Point3<S>; 8] {
+        let [x, y, z] = self.axes();
+        let (x, y, z) = (
+            x * self.half_extents.x,
+            y * self.half_extents.y,
+            z * self.half_extents.z,
+        );
+        let c = self.center;
+        [
+            c - x - y - z,
+            c + x - y - z,
+            c - x + y - z,
+            c + x + y - z,
+            c - x - y + z,
+            c + x - y + z,
+            c - x + y + z,
+            c + x + y + z,
+        ]
+    }
+
+    /// Returns `true` if the point lies inside the box or on its boundary.
+    pub fn contains_point(&self, point: Point3<S>) -> bool {
+        let local = self.rot.invert().rotate_vector(point - self.center);
+        local.x.abs() <= self.half_extents.x
+            && local.y.abs() <= self.half_extents.y
+            && local.z.abs() <= self.half_extents.z
+    }
+
+    /// Returns the point inside the box that is closest to `point`.
+    pub fn closest_point(&self, point: Point3<S>) -> Point3<S> {
+        let local = self.rot.invert().rotate_vector(point - self.center);
+        let clamped = local
+            .zip(-self.half_extents, S::max)
+            .zip(self.half_extents, S::min);
+        self.center + self.rot.rotate_vector(clamped)
+    }
+
+    /// Returns `true` if this box and `other` overlap, using the separating
+    /// axis test from Christer Ericson's _Real-Time Collision Detection_.
+    pub fn intersects<R2: Rotation3<S>>(&self, other: &Obb3<S, R2>) -> bool {
+        let a = self.axes();
+        let b = other.axes();
+        let ea = self.half_extents;
+        let eb = other.half_extents;
+
+        // `other` expressed in the local space of `self`; the epsilon keeps
+        // the cross product axes from being treated as separating when two
+        // edges are nearly parallel
+        let eps: S = S::default_epsilon() * cast(16).unwrap();
+        let mut r = [[S::zero(); 3]; 3];
+        let mut abs_r = [[S::zero(); 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                r[i][j] = a[i].dot(b[j]);
+                abs_r[i][j] = r[i][j].abs() + eps;
+            }
+        }
+        let d = other.center - self.center;
+        let t = [d.dot(a[0]), d.dot(a[1]), d.dot(a[2])];
+
+        // the face normals of `self`
+        for i in 0..3 {
+            let rb = eb[0] * abs_r[i][0] + eb[1] * abs_r[i][1] + eb[2] * abs_r[i][2];
+            if t[i].abs() > ea[i] + rb {
+                return false;
+            }
+        }
+
+        // the face normals of `other`
+        for j in 0..3 {
+            let ra = ea[0] * abs_r[0][j] + ea[1] * abs_r[1][j] + ea[2] * abs_r[2][j];
+            let dist = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+            if dist.abs() > ra + eb[j] {
+                return false;
+            }
+        }
+
+        // the cross products of each pair of edges
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = ea[i1] * abs_r[i2][j] + ea[i2] * abs_r[i1][j];
+                let rb = eb[j1] * abs_r[i][j2] + eb[j2] * abs_r[i][j1];
+                let dist = t[i2] * r[i1][j] - t[i1] * r[i2][j];
+                if dist.abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl<S: BaseFloat, R: approx::AbsDiffEq<Epsilon = S>> approx::AbsDiffEq for Obb3<S, R> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Point3::abs_diff_eq(&self.center, &other.center, epsilon)
+            && Vector3::abs_diff_eq(&self.half_extents, &other.half_extents, epsilon)
+            && R::abs_diff_eq(&self.rot, &other.rot, epsilon)
+    }
+}
+
+impl<S: BaseFloat, R: approx::RelativeEq<Epsilon = S>> approx::RelativeEq for Obb3<S, R> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Point3::relative_eq(&self.center, &other.center, epsilon, max_relative)
+            && Vector3::relative_eq(
+                &self.half_extents,
+                &other.half_extents,
+                epsilon,
+                max_relative,
+            )
+            && R::relative_eq(&self.rot, &other.rot, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat, R: approx::UlpsEq<Epsilon = S>> approx::UlpsEq for Obb3<S, R> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Point3::ulps_eq(&self.center, &other.center, epsilon, max_ulps)
+            && Vector3::ulps_eq(&self.half_extents, &other.half_extents, epsilon, max_ulps)
+            && R::ulps_eq(&self.rot, &other.rot, epsilon, max_ulps)
+    }
+}
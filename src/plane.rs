@@ -0,0 +1,195 @@
+// Copyright 2019 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Planes in 3-dimensional space, used for clipping, reflections and the
+//! sides of view frustums.
+
+use structure::*;
+
+use approx;
+use matrix::Matrix4;
+use num::BaseFloat;
+use point::Point3;
+use ray::Ray3;
+use vector::{Vector3, Vector4};
+
+/// A plane in 3-dimensional space, stored in
+/// [Hessian normal form](http://mathworld.wolfram.com/HessianNormalForm.html).
+///
+/// The plane contains all points `p` for which `normal.dot(p) + distance` is
+/// zero, where `normal` has unit length. `distance` is therefore the signed
+/// distance from the plane to the origin, measured along the normal.
+///
+/// The same convention is used when converting the plane to and from a
+/// `Vector4` with the components `(normal.x, normal.y, normal.z, distance)`,
+/// so that the plane equation becomes a dot product with the homogeneous
+/// representation of the point.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Plane<S> {
+    /// The unit normal of the plane.
+    pub normal: Vector3<S>,
+    /// The signed distance from the plane to the origin.
+    pub distance: S,
+}
+
+impl<S> Plane<S> {
+    /// Construct a plane from a unit normal and a distance, without
+    /// normalizing.
+    #[inline]
+    pub const fn new(normal: Vector3<S>, distance: S) -> Plane<S> {
+        Plane {
+            normal: normal,
+            distance: distance,
+        }
+    }
+}
+
+impl<S: BaseFloat> Plane<S> {
+    /// Construct a plane from the coefficients of the plane equation
+    /// `a * x + b * y + c * z + d = 0`, normalizing them.
+    #[inline]
+    pub fn from_abcd(a: S, b: S, c: S, d: S) -> Plane<S> {
+        Plane::new(Vector3::new(a, b, c), d).normalize()
+    }
+
+    /// Construct the plane passing through `point` and facing in the direction
+    /// of `normal`. The normal does not need to be normalized.
+    #[inline]
+    pub fn from_point_normal(point: Point3<S>, normal: Vector3<S>) -> Plane<S> {
+        let normal = normal.normalize();
+        Plane::new(normal, -point.dot(normal))
+    }
+
+    /// Construct the plane passing through the three points.
+    ///
+    /// The normal faces the side from which the points are seen in
+    /// counter-clockwise order. Returns `None` if the points are collinear.
+    pub fn from_points(a: Point3<S>, b: Point3<S>, c: Point3<S>) -> Option<Plane<S>> {
+        let (ab, ac) = (b - a, c - a);
+        let normal = ab.cross(ac);
+        // the squared sine of the angle at `a`, so that the test does not
+        // depend on the size of the triangle
+        if normal.magnitude2() <= S::default_epsilon() * ab.magnitude2() * ac.magnitude2() {
+            None
+        } else {
+            Some(Plane::from_point_normal(a, normal))
+        }
+    }
+
+    /// Returns the plane scaled so that its normal has unit length.
+    #[inline]
+    pub fn normalize(&self) -> Plane<S> {
+        let inv = S::one() / self.normal.magnitude();
+        Plane::new(self.normal * inv, self.distance * inv)
+    }
+
+    /// Returns the same plane, facing in the opposite direction.
+    #[inline]
+    pub fn flip(&self) -> Plane<S> {
+        Plane::new(-self.normal, -self.distance)
+    }
+
+    /// The signed distance from the plane to `point`. The distance is positive
+    /// if the point is on the side the normal is facing.
+    #[inline]
+    pub fn signed_distance(&self, point: Point3<S>) -> S {
+        point.dot(self.normal) + self.distance
+    }
+
+    /// Project `point` onto the plane, returning the closest point on the
+    /// plane.
+    #[inline]
+    pub fn project_point(&self, point: Point3<S>) -> Point3<S> {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    /// Transform the plane by the affine or projective transformation `m`,
+    /// by multiplying it with the inverse-transpose of `m`. Returns `None` if
+    /// `m` is not invertible.
+    pub fn transform(&self, m: &Matrix4<S>) -> Option<Plane<S>> {
+        m.invert().map(|inv| {
+            let v: Vector4<S> = (*self).into();
+            let v = inv.transpose() * v;
+            Plane::from(v)
+        })
+    }
+
+    /// Intersect a ray with the plane, returning the distance to the hit and
+    /// the point that was hit.
+    ///
+    /// See `Ray3::intersect_plane` for details.
+    #[inline]
+    pub fn intersect_ray(&self, ray: &Ray3<S>) -> Option<(S, Point3<S>)> {
+        ray.intersect_plane(self.normal, self.distance)
+    }
+}
+
+impl<S: BaseFloat> From<Vector4<S>> for Plane<S> {
+    /// Convert the `(a, b, c, d)` coefficients of a plane equation into a
+    /// plane, normalizing them.
+    #[inline]
+    fn from(v: Vector4<S>) -> Plane<S> {
+        Plane::from_abcd(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl<S: BaseFloat> From<Plane<S>> for Vector4<S> {
+    #[inline]
+    fn from(plane: Plane<S>) -> Vector4<S> {
+        plane.normal.extend(plane.distance)
+    }
+}
+
+impl<S: BaseFloat> approx::AbsDiffEq for Plane<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Vector3::abs_diff_eq(&self.normal, &other.normal, epsilon)
+            && S::abs_diff_eq(&self.distance, &other.distance, epsilon)
+    }
+}
+
+impl<S: BaseFloat> approx::RelativeEq for Plane<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Vector3::relative_eq(&self.normal, &other.normal, epsilon, max_relative)
+            && S::relative_eq(&self.distance, &other.distance, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> approx::UlpsEq for Plane<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Vector3::ulps_eq(&self.normal, &other.normal, epsilon, max_ulps)
+            && S::ulps_eq(&self.distance, &other.distance, epsilon, max_ulps)
+    }
+}
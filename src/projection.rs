@@ -13,18 +13,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::error;
+use std::fmt;
+
 use num_traits::cast;
 use num_traits::Zero;
 
-use structure::Angle;
+use structure::*;
 
+use aabb::Aabb2;
 use angle::Rad;
 use matrix::Matrix4;
 use num::BaseFloat;
+use point::{Point2, Point3};
+use ray::Ray3;
+use vector::{Vector2, Vector4};
 
 /// Create a perspective projection matrix.
 ///
-/// This is the equivalent to the [`gluPerspective`] function.
+/// This is the equivalent to the [`gluPerspective`] function, and uses
+/// OpenGL's clip space. See `perspective_with` for other conventions.
 ///
 /// [`gluPerspective`]: https://www.opengl.org/sdk/docs/man2/xhtml/gluPerspective.xml
 pub fn perspective<S: BaseFloat, A: Into<Rad<S>>>(
@@ -32,22 +40,48 @@ pub fn perspective<S: BaseFloat, A: Into<Rad<S>>>(
     aspect: S,
     near: S,
     far: S,
+) -> Matrix4<S> {
+    perspective_with(fovy, aspect, near, far, ClipSpace::OPENGL)
+}
+
+/// Create a perspective projection matrix for the given clip space.
+pub fn perspective_with<S: BaseFloat, A: Into<Rad<S>>>(
+    fovy: A,
+    aspect: S,
+    near: S,
+    far: S,
+    clip_space: ClipSpace,
 ) -> Matrix4<S> {
     PerspectiveFov {
         fovy: fovy.into(),
         aspect: aspect,
         near: near,
         far: far,
+        clip_space: clip_space,
     }
     .into()
 }
 
 /// Create a perspective matrix from a view frustum.
 ///
-/// This is the equivalent of the now deprecated [`glFrustum`] function.
+/// This is the equivalent of the now deprecated [`glFrustum`] function, and
+/// uses OpenGL's clip space. See `frustum_with` for other conventions.
 ///
 /// [`glFrustum`]: http://www.opengl.org/sdk/docs/man2/xhtml/glFrustum.xml
 pub fn frustum<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4<S> {
+    frustum_with(left, right, bottom, top, near, far, ClipSpace::OPENGL)
+}
+
+/// Create a perspective matrix from a view frustum, for the given clip space.
+pub fn frustum_with<S: BaseFloat>(
+    left: S,
+    right: S,
+    bottom: S,
+    top: S,
+    near: S,
+    far: S,
+    clip_space: ClipSpace,
+) -> Matrix4<S> {
     Perspective {
         left: left,
         right: right,
@@ -55,16 +89,31 @@ pub fn frustum<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far:
         top: top,
         near: near,
         far: far,
+        clip_space: clip_space,
     }
     .into()
 }
 
 /// Create an orthographic projection matrix.
 ///
-/// This is the equivalent of the now deprecated [`glOrtho`] function.
+/// This is the equivalent of the now deprecated [`glOrtho`] function, and
+/// uses OpenGL's clip space. See `ortho_with` for other conventions.
 ///
 /// [`glOrtho`]: http://www.opengl.org/sdk/docs/man2/xhtml/glOrtho.xml
 pub fn ortho<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4<S> {
+    ortho_with(left, right, bottom, top, near, far, ClipSpace::OPENGL)
+}
+
+/// Create an orthographic projection matrix for the given clip space.
+pub fn ortho_with<S: BaseFloat>(
+    left: S,
+    right: S,
+    bottom: S,
+    top: S,
+    near: S,
+    far: S,
+    clip_space: ClipSpace,
+) -> Matrix4<S> {
     Ortho {
         left: left,
         right: right,
@@ -72,10 +121,455 @@ pub fn ortho<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S
         top: top,
         near: near,
         far: far,
+        clip_space: clip_space,
     }
     .into()
 }
 
+/// Map a point to window coordinates, returning `None` if it lies on the
+/// plane of the camera, where it has no projection.
+///
+/// This is the equivalent of the [`gluProject`] function. `view_proj` maps
+/// the point to clip space, which is then mapped to `viewport` with the `y`
+/// axis pointing upwards. The window depth ranges from `0` to `1`, whatever
+/// the depth range of `clip_space`. Window systems where `y` points downwards
+/// need to subtract the result from the bottom of the viewport.
+///
+/// [`gluProject`]: https://www.khronos.org/registry/OpenGL-Refpages/gl2.1/xhtml/gluProject.xml
+pub fn project<S: BaseFloat>(
+    point: Point3<S>,
+    view_proj: Matrix4<S>,
+    viewport: Aabb2<S>,
+    clip_space: ClipSpace,
+) -> Option<Point3<S>> {
+    let clip = view_proj * point.to_homogeneous();
+    if clip.w == S::zero() {
+        return None;
+    }
+    let ndc = Point3::from_homogeneous(clip);
+
+    let half: S = cast(0.5f64).unwrap();
+    let min_depth: S = clip_space.min_depth();
+    let size = viewport.extents();
+    Some(Point3::new(
+        viewport.min.x + (ndc.x + S::one()) * half * size.x,
+        viewport.min.y + (ndc.y + S::one()) * half * size.y,
+        (ndc.z - min_depth) / (S::one() - min_depth),
+    ))
+}
+
+/// Map a point in window coordinates back to the space that `view_proj`
+/// projects from, returning `None` if `view_proj` is not invertible.
+///
+/// This is the equivalent of the [`gluUnProject`] function, and the inverse
+/// of `project`. When unprojecting many points with the same matrix, it is
+/// faster to invert the matrix once, or use the `inverse_matrix` of the
+/// projection, and transform the points in normalized device coordinates
+/// directly.
+///
+/// [`gluUnProject`]: https://www.khronos.org/registry/OpenGL-Refpages/gl2.1/xhtml/gluUnProject.xml
+pub fn unproject<S: BaseFloat>(
+    window: Point3<S>,
+    view_proj: Matrix4<S>,
+    viewport: Aabb2<S>,
+    clip_space: ClipSpace,
+) -> Option<Point3<S>> {
+    let inv = view_proj.invert()?;
+    let ndc = window_to_ndc(window, viewport, clip_space);
+    unproject_ndc(inv, ndc)
+}
+
+/// The ray through a pixel in window coordinates, starting on the near plane
+/// and heading away from the camera, with a normalized direction. Returns
+/// `None` if `view_proj` is not invertible.
+///
+/// This is mostly useful for picking objects with the mouse. The ray is in
+/// the space that `view_proj` projects from, so if `view_proj` is a combined
+/// view-projection matrix it is in world space.
+pub fn picking_ray<S: BaseFloat>(
+    window: Point2<S>,
+    view_proj: Matrix4<S>,
+    viewport: Aabb2<S>,
+    clip_space: ClipSpace,
+) -> Option<Ray3<S>> {
+    let inv = view_proj.invert()?;
+    let ndc = window_to_ndc(
+        Point3::new(window.x, window.y, S::zero()),
+        viewport,
+        clip_space,
+    );
+
+    // the point halfway through the depth range is at a finite distance even
+    // when the far plane is at infinity
+    let half: S = cast(0.5f64).unwrap();
+    let near_depth: S = clip_space.near_depth();
+    let middle_depth = (near_depth + clip_space.far_depth()) * half;
+    let near = unproject_ndc(inv, Point3::new(ndc.x, ndc.y, near_depth))?;
+    let middle = unproject_ndc(inv, Point3::new(ndc.x, ndc.y, middle_depth))?;
+    Some(Ray3::new(near, (middle - near).normalize()))
+}
+
+/// Replace the near plane of a perspective projection with an arbitrary
+/// clip plane, using the method described by Eric Lengyel in [Oblique View
+/// Frustum Depth Projection and Clipping](http://www.terathon.com/lengyel/Lengyel-Oblique.pdf).
+///
+/// This is mostly useful for rendering planar reflections and portals, where
+/// everything between the camera and the mirror or portal must be clipped.
+/// `clip_plane` is in view space and its normal must face away from the
+/// camera, so that the camera is on its negative side. Points on the plane
+/// are mapped to the near depth of `clip_space`, and the far plane is tilted
+/// so that it still contains the far corners of the frustum, which costs
+/// some depth precision when the planes are close to perpendicular.
+///
+/// `proj` must be a perspective projection built with the same `clip_space`
+/// by one of the projection types in this module.
+pub fn oblique_near_plane<S: BaseFloat, P: Into<Vector4<S>>>(
+    proj: Matrix4<S>,
+    clip_plane: P,
+    clip_space: ClipSpace,
+) -> Matrix4<S> {
+    let c: Vector4<S> = clip_plane.into();
+    let near_depth: S = clip_space.near_depth();
+    let far_depth: S = clip_space.far_depth();
+
+    // the corner of the frustum on the far plane that lies furthest along
+    // the clip plane's normal, mapped back to view space through the known
+    // structure of the projection rather than a full matrix inverse
+    let corner_x = (c.x / proj.x.x).signum();
+    let corner_y = (c.y / proj.y.y).signum();
+    let qz = S::one() / proj.z.w;
+    let q = Vector4::new(
+        (corner_x - proj.z.x * qz) / proj.x.x,
+        (corner_y - proj.z.y * qz) / proj.y.y,
+        qz,
+        (far_depth - proj.z.z * qz) / proj.w.z,
+    );
+
+    // the new depth row, which maps points on the plane to the near depth
+    // and `q` to the far depth
+    let scale = (far_depth - near_depth) / c.dot(q);
+    let row = c * scale + proj.row(3) * near_depth;
+
+    let mut result = proj;
+    result.x.z = row.x;
+    result.y.z = row.y;
+    result.z.z = row.z;
+    result.w.z = row.w;
+    result
+}
+
+fn window_to_ndc<S: BaseFloat>(
+    window: Point3<S>,
+    viewport: Aabb2<S>,
+    clip_space: ClipSpace,
+) -> Point3<S> {
+    let two: S = cast(2).unwrap();
+    let min_depth: S = clip_space.min_depth();
+    let size = viewport.extents();
+    Point3::new(
+        (window.x - viewport.min.x) * two / size.x - S::one(),
+        (window.y - viewport.min.y) * two / size.y - S::one(),
+        window.z * (S::one() - min_depth) + min_depth,
+    )
+}
+
+fn unproject_ndc<S: BaseFloat>(inv: Matrix4<S>, ndc: Point3<S>) -> Option<Point3<S>> {
+    let v = inv * Vector4::new(ndc.x, ndc.y, ndc.z, S::one());
+    if v.w == S::zero() {
+        None
+    } else {
+        Some(Point3::from_homogeneous(v))
+    }
+}
+
+/// The range of depth values in normalized device coordinates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DepthRange {
+    /// Depth goes from `-1` to `1`, as in OpenGL.
+    NegativeOneToOne,
+    /// Depth goes from `0` to `1`, as in Direct3D, Vulkan, Metal and wgpu.
+    ZeroToOne,
+}
+
+/// The orientation of view space, relative to the direction the camera is
+/// looking in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Handedness {
+    /// The camera looks along the negative `z` axis, as in OpenGL.
+    RightHanded,
+    /// The camera looks along the positive `z` axis, as is common with
+    /// Direct3D.
+    LeftHanded,
+}
+
+/// The conventions of the clip space that a projection maps view space to.
+///
+/// The `x` and `y` coordinates always range from `-1` to `1`, from left to
+/// right and from bottom to top.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ClipSpace {
+    pub depth: DepthRange,
+    pub handedness: Handedness,
+    /// Map the near plane to the largest depth value and the far plane to
+    /// the smallest, instead of the other way around. Combined with
+    /// `DepthRange::ZeroToOne` and a floating point depth buffer, this
+    /// greatly improves the precision of distant depth values.
+    pub reverse_z: bool,
+}
+
+impl ClipSpace {
+    /// OpenGL's clip space, which is used by default.
+    pub const OPENGL: ClipSpace = ClipSpace {
+        depth: DepthRange::NegativeOneToOne,
+        handedness: Handedness::RightHanded,
+        reverse_z: false,
+    };
+
+    /// Create a new clip space description.
+    #[inline]
+    pub const fn new(depth: DepthRange, handedness: Handedness, reverse_z: bool) -> ClipSpace {
+        ClipSpace {
+            depth: depth,
+            handedness: handedness,
+            reverse_z: reverse_z,
+        }
+    }
+
+    /// The depth that points on the near plane are mapped to.
+    #[inline]
+    pub fn near_depth<S: BaseFloat>(&self) -> S {
+        if self.reverse_z {
+            S::one()
+        } else {
+            self.min_depth()
+        }
+    }
+
+    /// The depth that points on the far plane are mapped to.
+    #[inline]
+    pub fn far_depth<S: BaseFloat>(&self) -> S {
+        if self.reverse_z {
+            self.min_depth()
+        } else {
+            S::one()
+        }
+    }
+
+    /// The `z` coordinate in view space of a point one unit in front of the
+    /// camera.
+    #[inline]
+    pub fn forward<S: BaseFloat>(&self) -> S {
+        match self.handedness {
+            Handedness::RightHanded => -S::one(),
+            Handedness::LeftHanded => S::one(),
+        }
+    }
+
+    #[inline]
+    fn min_depth<S: BaseFloat>(&self) -> S {
+        match self.depth {
+            DepthRange::NegativeOneToOne => -S::one(),
+            DepthRange::ZeroToOne => S::zero(),
+        }
+    }
+}
+
+impl Default for ClipSpace {
+    #[inline]
+    fn default() -> ClipSpace {
+        ClipSpace::OPENGL
+    }
+}
+
+/// The reason that a projection could not be turned into a matrix.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ProjectionError<S> {
+    /// The vertical field of view is not between zero and a half turn.
+    FovyOutOfRange(Rad<S>),
+    /// The aspect ratio is not positive and finite. This happens when the
+    /// height of a window is zero.
+    AspectOutOfRange(S),
+    /// The near plane is not in front of the camera.
+    NearNotPositive(S),
+    /// The left and right planes are in the same place, or for perspective
+    /// projections, the right plane is to the left of the left plane.
+    EmptyWidth { left: S, right: S },
+    /// The bottom and top planes are in the same place, or for perspective
+    /// projections, the top plane is below the bottom plane.
+    EmptyHeight { bottom: S, top: S },
+    /// The near and far planes are in the same place, or for perspective
+    /// projections, the far plane is closer than the near plane.
+    EmptyDepth { near: S, far: S },
+    /// One of the angles of an asymmetric field of view is not strictly
+    /// between minus and plus a quarter turn.
+    AngleOutOfRange(Rad<S>),
+}
+
+impl<S: fmt::Debug> fmt::Display for ProjectionError<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ProjectionError::FovyOutOfRange(ref fovy) => write!(
+                f,
+                "The vertical field of view must be between zero and a half turn, found: {:?}",
+                fovy
+            ),
+            ProjectionError::AspectOutOfRange(ref aspect) => write!(
+                f,
+                "The aspect ratio must be positive and finite, found: {:?}",
+                aspect
+            ),
+            ProjectionError::NearNotPositive(ref near) => write!(
+                f,
+                "The near plane distance must be above zero, found: {:?}",
+                near
+            ),
+            ProjectionError::EmptyWidth {
+                ref left,
+                ref right,
+            } => write!(
+                f,
+                "The projection has no width, found: left: {:?} right: {:?}",
+                left, right
+            ),
+            ProjectionError::EmptyHeight {
+                ref bottom,
+                ref top,
+            } => write!(
+                f,
+                "The projection has no height, found: bottom: {:?} top: {:?}",
+                bottom, top
+            ),
+            ProjectionError::EmptyDepth { ref near, ref far } => write!(
+                f,
+                "The projection has no depth, found: near: {:?} far: {:?}",
+                near, far
+            ),
+            ProjectionError::AngleOutOfRange(ref angle) => write!(
+                f,
+                "The field of view angles must be within a quarter turn, found: {:?}",
+                angle
+            ),
+        }
+    }
+}
+
+impl<S: fmt::Debug> error::Error for ProjectionError<S> {}
+
+/// Unwrap a projection matrix for the `From` conversions, which panic with
+/// the message of the error.
+fn expect_matrix<S: fmt::Debug>(result: Result<Matrix4<S>, ProjectionError<S>>) -> Matrix4<S> {
+    match result {
+        Ok(m) => m,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// The coefficients `a` and `b` of a perspective projection that maps a
+/// point at `distance` in front of the camera to the depth
+/// `a + b / distance` in normalized device coordinates. The far plane is at
+/// infinity if `far` is `None`.
+fn perspective_depth<S: BaseFloat>(near: S, far: Option<S>, clip_space: ClipSpace) -> (S, S) {
+    let near_depth: S = clip_space.near_depth();
+    let far_depth: S = clip_space.far_depth();
+    match far {
+        Some(far) => (
+            (far_depth * far - near_depth * near) / (far - near),
+            (near_depth - far_depth) * near * far / (far - near),
+        ),
+        None => (far_depth, (near_depth - far_depth) * near),
+    }
+}
+
+/// Build a perspective projection that maps `x / distance` to
+/// `x * x_scale + x_offset` in normalized device coordinates, and likewise
+/// for `y`, where `distance` is the distance in front of the camera.
+fn perspective_matrix<S: BaseFloat>(
+    x_scale: S,
+    y_scale: S,
+    x_offset: S,
+    y_offset: S,
+    near: S,
+    far: Option<S>,
+    clip_space: ClipSpace,
+) -> Matrix4<S> {
+    let forward: S = clip_space.forward();
+    let (a, b) = perspective_depth(near, far, clip_space);
+
+    let c0r0 = x_scale;
+    let c0r1 = S::zero();
+    let c0r2 = S::zero();
+    let c0r3 = S::zero();
+
+    let c1r0 = S::zero();
+    let c1r1 = y_scale;
+    let c1r2 = S::zero();
+    let c1r3 = S::zero();
+
+    let c2r0 = x_offset * forward;
+    let c2r1 = y_offset * forward;
+    let c2r2 = a * forward;
+    let c2r3 = forward;
+
+    let c3r0 = S::zero();
+    let c3r1 = S::zero();
+    let c3r2 = b;
+    let c3r3 = S::zero();
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    Matrix4::new(
+        c0r0, c0r1, c0r2, c0r3,
+        c1r0, c1r1, c1r2, c1r3,
+        c2r0, c2r1, c2r2, c2r3,
+        c3r0, c3r1, c3r2, c3r3,
+    )
+}
+
+/// The inverse of the matrix built by `perspective_matrix` with the same
+/// arguments, mapping clip space back to view space.
+fn perspective_inverse<S: BaseFloat>(
+    x_scale: S,
+    y_scale: S,
+    x_offset: S,
+    y_offset: S,
+    near: S,
+    far: Option<S>,
+    clip_space: ClipSpace,
+) -> Matrix4<S> {
+    let forward: S = clip_space.forward();
+    let (a, b) = perspective_depth(near, far, clip_space);
+
+    let c0r0 = S::one() / x_scale;
+    let c0r1 = S::zero();
+    let c0r2 = S::zero();
+    let c0r3 = S::zero();
+
+    let c1r0 = S::zero();
+    let c1r1 = S::one() / y_scale;
+    let c1r2 = S::zero();
+    let c1r3 = S::zero();
+
+    let c2r0 = S::zero();
+    let c2r1 = S::zero();
+    let c2r2 = S::zero();
+    let c2r3 = S::one() / b;
+
+    let c3r0 = -x_offset / x_scale;
+    let c3r1 = -y_offset / y_scale;
+    let c3r2 = forward;
+    let c3r3 = -a / b;
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    Matrix4::new(
+        c0r0, c0r1, c0r2, c0r3,
+        c1r0, c1r1, c1r2, c1r3,
+        c2r0, c2r1, c2r2, c2r3,
+        c3r0, c3r1, c3r2, c3r3,
+    )
+}
+
 /// A perspective projection based on a vertical field-of-view angle.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
@@ -85,6 +579,8 @@ pub struct PerspectiveFov<S> {
     pub aspect: S,
     pub near: S,
     pub far: S,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub clip_space: ClipSpace,
 }
 
 impl<S: BaseFloat> PerspectiveFov<S> {
@@ -101,75 +597,307 @@ impl<S: BaseFloat> PerspectiveFov<S> {
             top: ymax,
             near: self.near.clone(),
             far: self.far.clone(),
+            clip_space: self.clip_space,
+        }
+    }
+
+    /// The projection matrix, moved by a fraction of a pixel for temporal
+    /// anti-aliasing. See `Perspective::jitter` for details.
+    #[inline]
+    pub fn jittered_matrix(&self, offset: Vector2<S>, viewport_size: Vector2<S>) -> Matrix4<S> {
+        self.to_perspective().jittered_matrix(offset, viewport_size)
+    }
+
+    /// The inverse of the projection matrix, calculated directly as for
+    /// `Perspective::inverse_matrix`.
+    pub fn inverse_matrix(&self) -> Matrix4<S> {
+        let two: S = cast(2).unwrap();
+        let f = Rad::cot(self.fovy / two);
+
+        perspective_inverse(
+            f / self.aspect,
+            f,
+            S::zero(),
+            S::zero(),
+            self.near,
+            Some(self.far),
+            self.clip_space,
+        )
+    }
+
+    /// Convert a depth value back to a distance from the camera. See
+    /// `Perspective::linear_depth`.
+    pub fn linear_depth(&self, ndc_depth: S) -> S {
+        let (a, b) = perspective_depth(self.near, Some(self.far), self.clip_space);
+        b / (ndc_depth - a)
+    }
+
+    /// Build the projection matrix, or return an error. See
+    /// `Perspective::try_into_matrix`.
+    pub fn try_into_matrix(&self) -> Result<Matrix4<S>, ProjectionError<S>> {
+        self.check(true).map(|_| self.matrix())
+    }
+
+    /// Check the parameters. Unless `strict` is set, an infinite aspect ratio
+    /// is allowed, as it always has been when converting into a `Matrix4`.
+    fn check(&self, strict: bool) -> Result<(), ProjectionError<S>> {
+        if self.fovy <= Rad::zero() || self.fovy >= Rad::turn_div_2() || self.fovy.0.is_nan() {
+            return Err(ProjectionError::FovyOutOfRange(self.fovy));
         }
+        let infinite_aspect = strict && self.aspect.is_infinite();
+        if self.aspect <= S::zero() || self.aspect.is_nan() || infinite_aspect {
+            return Err(ProjectionError::AspectOutOfRange(self.aspect));
+        }
+        if self.near <= S::zero() || self.near.is_nan() {
+            return Err(ProjectionError::NearNotPositive(self.near));
+        }
+        if self.far <= self.near || self.far.is_nan() {
+            return Err(ProjectionError::EmptyDepth {
+                near: self.near,
+                far: self.far,
+            });
+        }
+        Ok(())
+    }
+
+    fn matrix(&self) -> Matrix4<S> {
+        let two: S = cast(2).unwrap();
+        let f = Rad::cot(self.fovy / two);
+
+        perspective_matrix(
+            f / self.aspect,
+            f,
+            S::zero(),
+            S::zero(),
+            self.near,
+            Some(self.far),
+            self.clip_space,
+        )
     }
 }
 
 impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
+    /// # Panics
+    ///
+    /// If the parameters are invalid. This accepts an infinite aspect ratio,
+    /// unlike `try_into_matrix`, which returns an error instead of panicking.
     fn from(persp: PerspectiveFov<S>) -> Matrix4<S> {
-        assert!(
-            persp.fovy > Rad::zero(),
-            "The vertical field of view cannot be below zero, found: {:?}",
-            persp.fovy
-        );
-        assert!(
-            persp.fovy < Rad::turn_div_2(),
-            "The vertical field of view cannot be greater than a half turn, found: {:?}",
-            persp.fovy
-        );
-        assert!(
-            persp.aspect > S::zero(),
-            "The aspect ratio cannot be below zero, found: {:?}",
-            persp.aspect
-        );
-        assert!(
-            persp.near > S::zero(),
-            "The near plane distance cannot be below zero, found: {:?}",
-            persp.near
-        );
-        assert!(
-            persp.far > S::zero(),
-            "The far plane distance cannot be below zero, found: {:?}",
-            persp.far
-        );
-        assert!(
-            persp.far > persp.near,
-            "The far plane cannot be closer than the near plane, found: far: {:?}, near: {:?}",
-            persp.far,
-            persp.near
-        );
+        expect_matrix(persp.check(false).map(|_| persp.matrix()))
+    }
+}
 
+/// A perspective projection based on a vertical field-of-view angle, with
+/// the far plane at infinity.
+///
+/// This is the limit of `PerspectiveFov` as `far` goes to infinity, so
+/// nothing in front of the near plane is ever clipped. Combined with
+/// `ClipSpace::reverse_z` and a floating point depth buffer, depth precision
+/// stays good all the way to the horizon.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InfinitePerspectiveFov<S> {
+    pub fovy: Rad<S>,
+    pub aspect: S,
+    pub near: S,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub clip_space: ClipSpace,
+}
+
+impl<S: BaseFloat> InfinitePerspectiveFov<S> {
+    /// The inverse of the projection matrix, calculated directly as for
+    /// `Perspective::inverse_matrix`.
+    pub fn inverse_matrix(&self) -> Matrix4<S> {
         let two: S = cast(2).unwrap();
-        let f = Rad::cot(persp.fovy / two);
+        let f = Rad::cot(self.fovy / two);
 
-        let c0r0 = f / persp.aspect;
-        let c0r1 = S::zero();
-        let c0r2 = S::zero();
-        let c0r3 = S::zero();
+        perspective_inverse(
+            f / self.aspect,
+            f,
+            S::zero(),
+            S::zero(),
+            self.near,
+            None,
+            self.clip_space,
+        )
+    }
 
-        let c1r0 = S::zero();
-        let c1r1 = f;
-        let c1r2 = S::zero();
-        let c1r3 = S::zero();
+    /// Convert a depth value back to a distance from the camera. See
+    /// `Perspective::linear_depth`.
+    ///
+    /// The far depth value maps to infinity.
+    pub fn linear_depth(&self, ndc_depth: S) -> S {
+        let (a, b) = perspective_depth(self.near, None, self.clip_space);
+        if ndc_depth == a {
+            S::infinity()
+        } else {
+            b / (ndc_depth - a)
+        }
+    }
 
-        let c2r0 = S::zero();
-        let c2r1 = S::zero();
-        let c2r2 = (persp.far + persp.near) / (persp.near - persp.far);
-        let c2r3 = -S::one();
+    /// Build the projection matrix, or return an error. See
+    /// `Perspective::try_into_matrix`.
+    pub fn try_into_matrix(&self) -> Result<Matrix4<S>, ProjectionError<S>> {
+        if self.fovy <= Rad::zero() || self.fovy >= Rad::turn_div_2() || self.fovy.0.is_nan() {
+            return Err(ProjectionError::FovyOutOfRange(self.fovy));
+        }
+        if self.aspect <= S::zero() || !self.aspect.is_finite() {
+            return Err(ProjectionError::AspectOutOfRange(self.aspect));
+        }
+        if self.near <= S::zero() || self.near.is_nan() {
+            return Err(ProjectionError::NearNotPositive(self.near));
+        }
 
-        let c3r0 = S::zero();
-        let c3r1 = S::zero();
-        let c3r2 = (two * persp.far * persp.near) / (persp.near - persp.far);
-        let c3r3 = S::zero();
+        let two: S = cast(2).unwrap();
+        let f = Rad::cot(self.fovy / two);
 
-        #[cfg_attr(rustfmt, rustfmt_skip)]
-        Matrix4::new(
-            c0r0, c0r1, c0r2, c0r3,
-            c1r0, c1r1, c1r2, c1r3,
-            c2r0, c2r1, c2r2, c2r3,
-            c3r0, c3r1, c3r2, c3r3,
+        Ok(perspective_matrix(
+            f / self.aspect,
+            f,
+            S::zero(),
+            S::zero(),
+            self.near,
+            None,
+            self.clip_space,
+        ))
+    }
+}
+
+impl<S: BaseFloat> From<InfinitePerspectiveFov<S>> for Matrix4<S> {
+    /// # Panics
+    ///
+    /// If the parameters are invalid. See `try_into_matrix` for a version
+    /// that returns an error instead.
+    fn from(persp: InfinitePerspectiveFov<S>) -> Matrix4<S> {
+        expect_matrix(persp.try_into_matrix())
+    }
+}
+
+/// A perspective projection with a separate angle for each side of the
+/// field of view, as used by VR and AR headsets.
+///
+/// The angles are measured from the view direction, so `left` and `down` are
+/// usually negative, matching the field of view reported by OpenXR.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AsymmetricPerspectiveFov<S> {
+    pub left: Rad<S>,
+    pub right: Rad<S>,
+    pub up: Rad<S>,
+    pub down: Rad<S>,
+    pub near: S,
+    pub far: S,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub clip_space: ClipSpace,
+}
+
+impl<S: BaseFloat> AsymmetricPerspectiveFov<S> {
+    /// Construct a field of view from the signed tangents of its angles,
+    /// using OpenGL's clip space. See `from_tangents_with` for other
+    /// conventions.
+    ///
+    /// As with the angles, `left` and `down` are negative when those sides
+    /// of the field of view lie left of and below the view direction. VR
+    /// SDKs do not all report their tangents this way:
+    ///
+    /// - OpenVR's `GetProjectionRaw` measures from the top of the screen, so
+    ///   its top and bottom tangents need to be negated and passed as `up`
+    ///   and `down`.
+    /// - The Oculus SDK's `ovrFovPort` gives all four tangents as positive
+    ///   values, so `LeftTan` and `DownTan` need to be negated.
+    pub fn from_tangents(
+        left: S,
+        right: S,
+        up: S,
+        down: S,
+        near: S,
+        far: S,
+    ) -> AsymmetricPerspectiveFov<S> {
+        AsymmetricPerspectiveFov::from_tangents_with(
+            left,
+            right,
+            up,
+            down,
+            near,
+            far,
+            ClipSpace::OPENGL,
         )
     }
+
+    /// Construct a field of view from the signed tangents of its angles, for
+    /// the given clip space.
+    pub fn from_tangents_with(
+        left: S,
+        right: S,
+        up: S,
+        down: S,
+        near: S,
+        far: S,
+        clip_space: ClipSpace,
+    ) -> AsymmetricPerspectiveFov<S> {
+        AsymmetricPerspectiveFov {
+            left: Rad::atan(left),
+            right: Rad::atan(right),
+            up: Rad::atan(up),
+            down: Rad::atan(down),
+            near: near,
+            far: far,
+            clip_space: clip_space,
+        }
+    }
+
+    pub fn to_perspective(&self) -> Perspective<S> {
+        Perspective {
+            left: self.near * Rad::tan(self.left),
+            right: self.near * Rad::tan(self.right),
+            bottom: self.near * Rad::tan(self.down),
+            top: self.near * Rad::tan(self.up),
+            near: self.near,
+            far: self.far,
+            clip_space: self.clip_space,
+        }
+    }
+
+    /// The inverse of the projection matrix. See
+    /// `Perspective::inverse_matrix`.
+    #[inline]
+    pub fn inverse_matrix(&self) -> Matrix4<S> {
+        self.to_perspective().inverse_matrix()
+    }
+
+    /// Convert a depth value back to a distance from the camera. See
+    /// `Perspective::linear_depth`.
+    #[inline]
+    pub fn linear_depth(&self, ndc_depth: S) -> S {
+        self.to_perspective().linear_depth(ndc_depth)
+    }
+
+    /// Build the projection matrix, or return an error. See
+    /// `Perspective::try_into_matrix`.
+    pub fn try_into_matrix(&self) -> Result<Matrix4<S>, ProjectionError<S>> {
+        for &angle in &[self.left, self.right, self.up, self.down] {
+            if angle.0.abs() >= Rad::turn_div_4().0 || angle.0.is_nan() {
+                return Err(ProjectionError::AngleOutOfRange(angle));
+            }
+        }
+        self.to_perspective().try_into_matrix()
+    }
+}
+
+impl<S: BaseFloat> From<AsymmetricPerspectiveFov<S>> for Perspective<S> {
+    #[inline]
+    fn from(fov: AsymmetricPerspectiveFov<S>) -> Perspective<S> {
+        fov.to_perspective()
+    }
+}
+
+impl<S: BaseFloat> From<AsymmetricPerspectiveFov<S>> for Matrix4<S> {
+    /// # Panics
+    ///
+    /// If the parameters are invalid. See `try_into_matrix` for a version
+    /// that returns an error instead.
+    fn from(fov: AsymmetricPerspectiveFov<S>) -> Matrix4<S> {
+        expect_matrix(fov.try_into_matrix())
+    }
 }
 
 /// A perspective projection with arbitrary left/right/bottom/top distances
@@ -182,61 +910,173 @@ pub struct Perspective<S> {
     pub top: S,
     pub near: S,
     pub far: S,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub clip_space: ClipSpace,
 }
 
-impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
-    fn from(persp: Perspective<S>) -> Matrix4<S> {
-        assert!(
-            persp.left <= persp.right,
-            "`left` cannot be greater than `right`, found: left: {:?} right: {:?}",
-            persp.left,
-            persp.right
-        );
+impl<S: BaseFloat> Perspective<S> {
+    /// Move the frustum sideways by `offset` pixels in a viewport that is
+    /// `viewport_size` pixels large, with `y` pointing upwards.
+    ///
+    /// The scene is then sampled at `offset` from the centres of the pixels
+    /// of the unmoved projection, which is used to gather sub-pixel detail
+    /// over several frames for temporal anti-aliasing. The offsets are
+    /// usually taken from a low-discrepancy sequence in the range `-0.5` to
+    /// `0.5`.
+    pub fn jitter(&self, offset: Vector2<S>, viewport_size: Vector2<S>) -> Perspective<S> {
+        let dx = offset.x * (self.right - self.left) / viewport_size.x;
+        let dy = offset.y * (self.top - self.bottom) / viewport_size.y;
+        Perspective {
+            left: self.left + dx,
+            right: self.right + dx,
+            bottom: self.bottom + dy,
+            top: self.top + dy,
+            ..*self
+        }
+    }
+
+    /// The projection matrix, moved by a fraction of a pixel for temporal
+    /// anti-aliasing. See `jitter` for details.
+    #[inline]
+    pub fn jittered_matrix(&self, offset: Vector2<S>, viewport_size: Vector2<S>) -> Matrix4<S> {
+        self.jitter(offset, viewport_size).into()
+    }
+
+    /// The part of the projection covering a single tile, when the image is
+    /// divided into a grid of `columns` by `rows` equally sized tiles.
+    ///
+    /// Tile `(0, 0)` is in the bottom left corner, with `i` counting columns
+    /// towards the right and `j` counting rows upwards. Rendering each tile
+    /// at the full resolution of the viewport gives an image `columns` by
+    /// `rows` times larger, which is useful for high resolution screenshots.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is not less than `columns` or `j` is not less than `rows`.
+    pub fn tile(&self, i: usize, j: usize, columns: usize, rows: usize) -> Perspective<S> {
         assert!(
-            persp.bottom <= persp.top,
-            "`bottom` cannot be greater than `top`, found: bottom: {:?} top: {:?}",
-            persp.bottom,
-            persp.top
+            i < columns,
+            "The tile column must be less than the number of columns, found: {} of {}",
+            i,
+            columns
         );
         assert!(
-            persp.near <= persp.far,
-            "`near` cannot be greater than `far`, found: near: {:?} far: {:?}",
-            persp.near,
-            persp.far
+            j < rows,
+            "The tile row must be less than the number of rows, found: {} of {}",
+            j,
+            rows
         );
 
+        let width = (self.right - self.left) / cast(columns).unwrap();
+        let height = (self.top - self.bottom) / cast(rows).unwrap();
+        let left = self.left + width * cast(i).unwrap();
+        let bottom = self.bottom + height * cast(j).unwrap();
+        Perspective {
+            left: left,
+            right: left + width,
+            bottom: bottom,
+            top: bottom + height,
+            ..*self
+        }
+    }
+
+    /// The inverse of the projection matrix, mapping clip space back to view
+    /// space.
+    ///
+    /// This is calculated directly, rather than by inverting the projection
+    /// matrix, so it is both faster and more precise than `Matrix4::invert`.
+    pub fn inverse_matrix(&self) -> Matrix4<S> {
         let two: S = cast(2i8).unwrap();
+        let width = self.right - self.left;
+        let height = self.top - self.bottom;
 
-        let c0r0 = (two * persp.near) / (persp.right - persp.left);
-        let c0r1 = S::zero();
-        let c0r2 = S::zero();
-        let c0r3 = S::zero();
+        perspective_inverse(
+            (two * self.near) / width,
+            (two * self.near) / height,
+            -(self.right + self.left) / width,
+            -(self.top + self.bottom) / height,
+            self.near,
+            Some(self.far),
+            self.clip_space,
+        )
+    }
 
-        let c1r0 = S::zero();
-        let c1r1 = (two * persp.near) / (persp.top - persp.bottom);
-        let c1r2 = S::zero();
-        let c1r3 = S::zero();
+    /// Convert a depth value in normalized device coordinates back to the
+    /// distance in front of the camera, along the view direction.
+    pub fn linear_depth(&self, ndc_depth: S) -> S {
+        let (a, b) = perspective_depth(self.near, Some(self.far), self.clip_space);
+        b / (ndc_depth - a)
+    }
 
-        let c2r0 = (persp.right + persp.left) / (persp.right - persp.left);
-        let c2r1 = (persp.top + persp.bottom) / (persp.top - persp.bottom);
-        let c2r2 = -(persp.far + persp.near) / (persp.far - persp.near);
-        let c2r3 = -S::one();
+    /// Build the projection matrix, or return an error naming the first
+    /// constraint that the parameters violate.
+    ///
+    /// This is stricter than converting a projection into a `Matrix4`, which
+    /// still accepts the degenerate parameters it always has, and panics with
+    /// the message of the error otherwise.
+    pub fn try_into_matrix(&self) -> Result<Matrix4<S>, ProjectionError<S>> {
+        self.check(true).map(|_| self.matrix())
+    }
 
-        let c3r0 = S::zero();
-        let c3r1 = S::zero();
-        let c3r2 = -(two * persp.far * persp.near) / (persp.far - persp.near);
-        let c3r3 = S::zero();
+    /// Check the parameters. Unless `strict` is set, a projection with no
+    /// width, height or depth, or with the near plane at the camera, is
+    /// allowed, as it always has been when converting into a `Matrix4`.
+    fn check(&self, strict: bool) -> Result<(), ProjectionError<S>> {
+        let width = self.right - self.left;
+        if width < S::zero() || width.is_nan() || (strict && width == S::zero()) {
+            return Err(ProjectionError::EmptyWidth {
+                left: self.left,
+                right: self.right,
+            });
+        }
+        let height = self.top - self.bottom;
+        if height < S::zero() || height.is_nan() || (strict && height == S::zero()) {
+            return Err(ProjectionError::EmptyHeight {
+                bottom: self.bottom,
+                top: self.top,
+            });
+        }
+        if strict && (self.near <= S::zero() || self.near.is_nan()) {
+            return Err(ProjectionError::NearNotPositive(self.near));
+        }
+        let depth = self.far - self.near;
+        if depth < S::zero() || depth.is_nan() || (strict && depth == S::zero()) {
+            return Err(ProjectionError::EmptyDepth {
+                near: self.near,
+                far: self.far,
+            });
+        }
+        Ok(())
+    }
 
-        #[cfg_attr(rustfmt, rustfmt_skip)]
-        Matrix4::new(
-            c0r0, c0r1, c0r2, c0r3,
-            c1r0, c1r1, c1r2, c1r3,
-            c2r0, c2r1, c2r2, c2r3,
-            c3r0, c3r1, c3r2, c3r3,
+    fn matrix(&self) -> Matrix4<S> {
+        let two: S = cast(2i8).unwrap();
+        let width = self.right - self.left;
+        let height = self.top - self.bottom;
+
+        perspective_matrix(
+            (two * self.near) / width,
+            (two * self.near) / height,
+            -(self.right + self.left) / width,
+            -(self.top + self.bottom) / height,
+            self.near,
+            Some(self.far),
+            self.clip_space,
         )
     }
 }
 
+impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
+    /// # Panics
+    ///
+    /// If `left` is greater than `right`, `bottom` is greater than `top` or
+    /// `near` is greater than `far`. See `try_into_matrix` for a stricter
+    /// version that returns an error instead.
+    fn from(persp: Perspective<S>) -> Matrix4<S> {
+        expect_matrix(persp.check(false).map(|_| persp.matrix()))
+    }
+}
+
 /// An orthographic projection with arbitrary left/right/bottom/top distances
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -247,30 +1087,36 @@ pub struct Ortho<S> {
     pub top: S,
     pub near: S,
     pub far: S,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub clip_space: ClipSpace,
 }
 
-impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
-    fn from(ortho: Ortho<S>) -> Matrix4<S> {
+impl<S: BaseFloat> Ortho<S> {
+    /// The inverse of the projection matrix, calculated directly as for
+    /// `Perspective::inverse_matrix`.
+    pub fn inverse_matrix(&self) -> Matrix4<S> {
         let two: S = cast(2).unwrap();
+        let (a, b) = self.depth();
+        let forward: S = self.clip_space.forward();
 
-        let c0r0 = two / (ortho.right - ortho.left);
+        let c0r0 = (self.right - self.left) / two;
         let c0r1 = S::zero();
         let c0r2 = S::zero();
         let c0r3 = S::zero();
 
         let c1r0 = S::zero();
-        let c1r1 = two / (ortho.top - ortho.bottom);
+        let c1r1 = (self.top - self.bottom) / two;
         let c1r2 = S::zero();
         let c1r3 = S::zero();
 
         let c2r0 = S::zero();
         let c2r1 = S::zero();
-        let c2r2 = -two / (ortho.far - ortho.near);
+        let c2r2 = forward / a;
         let c2r3 = S::zero();
 
-        let c3r0 = -(ortho.right + ortho.left) / (ortho.right - ortho.left);
-        let c3r1 = -(ortho.top + ortho.bottom) / (ortho.top - ortho.bottom);
-        let c3r2 = -(ortho.far + ortho.near) / (ortho.far - ortho.near);
+        let c3r0 = (self.right + self.left) / two;
+        let c3r1 = (self.top + self.bottom) / two;
+        let c3r2 = -forward * b / a;
         let c3r3 = S::one();
 
         #[cfg_attr(rustfmt, rustfmt_skip)]
@@ -281,4 +1127,98 @@ impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
             c3r0, c3r1, c3r2, c3r3,
         )
     }
+
+    /// Convert a depth value back to a distance from the camera. See
+    /// `Perspective::linear_depth`.
+    pub fn linear_depth(&self, ndc_depth: S) -> S {
+        let (a, b) = self.depth();
+        (ndc_depth - b) / a
+    }
+
+    /// The coefficients `a` and `b` of the projection, which maps a point at
+    /// `distance` in front of the camera to the depth `a * distance + b` in
+    /// normalized device coordinates.
+    fn depth(&self) -> (S, S) {
+        let near_depth: S = self.clip_space.near_depth();
+        let far_depth: S = self.clip_space.far_depth();
+        (
+            (far_depth - near_depth) / (self.far - self.near),
+            (near_depth * self.far - far_depth * self.near) / (self.far - self.near),
+        )
+    }
+
+    /// Build the projection matrix, or return an error. See
+    /// `Perspective::try_into_matrix`.
+    pub fn try_into_matrix(&self) -> Result<Matrix4<S>, ProjectionError<S>> {
+        self.check(true).map(|_| self.matrix())
+    }
+
+    /// Check the parameters. Unless `strict` is set, anything is allowed, as
+    /// it always has been when converting into a `Matrix4`.
+    fn check(&self, strict: bool) -> Result<(), ProjectionError<S>> {
+        if !strict {
+            return Ok(());
+        }
+        if !(self.right - self.left).is_normal() {
+            return Err(ProjectionError::EmptyWidth {
+                left: self.left,
+                right: self.right,
+            });
+        }
+        if !(self.top - self.bottom).is_normal() {
+            return Err(ProjectionError::EmptyHeight {
+                bottom: self.bottom,
+                top: self.top,
+            });
+        }
+        if !(self.far - self.near).is_normal() {
+            return Err(ProjectionError::EmptyDepth {
+                near: self.near,
+                far: self.far,
+            });
+        }
+        Ok(())
+    }
+
+    fn matrix(&self) -> Matrix4<S> {
+        let two: S = cast(2).unwrap();
+
+        let c0r0 = two / (self.right - self.left);
+        let c0r1 = S::zero();
+        let c0r2 = S::zero();
+        let c0r3 = S::zero();
+
+        let c1r0 = S::zero();
+        let c1r1 = two / (self.top - self.bottom);
+        let c1r2 = S::zero();
+        let c1r3 = S::zero();
+
+        let (a, b) = self.depth();
+
+        let c2r0 = S::zero();
+        let c2r1 = S::zero();
+        let c2r2 = a * self.clip_space.forward();
+        let c2r3 = S::zero();
+
+        let c3r0 = -(self.right + self.left) / (self.right - self.left);
+        let c3r1 = -(self.top + self.bottom) / (self.top - self.bottom);
+        let c3r2 = b;
+        let c3r3 = S::one();
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        Matrix4::new(
+            c0r0, c0r1, c0r2, c0r3,
+            c1r0, c1r1, c1r2, c1r3,
+            c2r0, c2r1, c2r2, c2r3,
+            c3r0, c3r1, c3r2, c3r3,
+        )
+    }
+}
+
+impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
+    /// This never panics, even for projections with no width, height or
+    /// depth. See `try_into_matrix` for a version that checks for those.
+    fn from(ortho: Ortho<S>) -> Matrix4<S> {
+        expect_matrix(ortho.check(false).map(|_| ortho.matrix()))
+    }
 }
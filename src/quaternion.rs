@@ -156,6 +156,206 @@ impl<S: BaseFloat> Quaternion<S> {
     pub fn is_finite(&self) -> bool {
         self.s.is_finite() && self.v.is_finite()
     }
+
+    /// The exponential of the quaternion.
+    ///
+    /// The exponential of a quaternion with a zero scalar part, `(0, v)`, is
+    /// the unit quaternion that rotates by twice the magnitude of `v` around
+    /// the direction of `v`. This is mostly used to integrate angular
+    /// velocities, where `(0, w * dt / 2)` gives the rotation over a time
+    /// step `dt`.
+    pub fn exp(self) -> Quaternion<S> {
+        let theta = self.v.magnitude();
+        let (sin, cos) = theta.sin_cos();
+        Quaternion::from_sv(cos, self.v * sinc(theta, sin)) * self.s.exp()
+    }
+
+    /// The natural logarithm of the quaternion, which is the inverse of
+    /// `exp`.
+    ///
+    /// The logarithm of a unit quaternion has a zero scalar part, and its
+    /// vector part is the rotation axis scaled by half the rotation angle.
+    /// Negative real quaternions have infinitely many logarithms, and for
+    /// those the one around the `x` axis is returned.
+    pub fn ln(self) -> Quaternion<S> {
+        let magnitude = self.magnitude();
+        let v_magnitude = self.v.magnitude();
+        let theta = v_magnitude.atan2(self.s);
+        let v = if v_magnitude > S::zero() {
+            // `atan2` keeps full precision for small angles, so this does not
+            // need a separate branch
+            self.v * (theta / v_magnitude)
+        } else {
+            Vector3::unit_x() * theta
+        };
+        Quaternion::from_sv(magnitude.ln(), v)
+    }
+
+    /// Raise the quaternion to the power of `n`.
+    ///
+    /// For unit quaternions this scales the rotation angle by `n`, so that
+    /// `a * (a.invert() * b).powf(t)` is the spherical linear interpolation
+    /// from `a` to `b`.
+    #[inline]
+    pub fn powf(self, n: S) -> Quaternion<S> {
+        (self.ln() * n).exp()
+    }
+
+    /// Spherical quadrangle interpolation between the quaternion and `other`,
+    /// by `amount`, shaped by the control points `a` and `b`.
+    ///
+    /// With control points from `squad_control_point` this gives a smooth
+    /// curve through a sequence of unit quaternions, without the sudden
+    /// changes in angular velocity of `slerp` at each keyframe. See
+    /// `squad_spline` to evaluate such a curve directly.
+    ///
+    /// - [Ken Shoemake, Animating Rotation with Quaternion Curves]
+    ///   (http://www.cs.cmu.edu/~kiranb/animation/p245-shoemake.pdf)
+    pub fn squad(
+        self,
+        a: Quaternion<S>,
+        b: Quaternion<S>,
+        other: Quaternion<S>,
+        amount: S,
+    ) -> Quaternion<S> {
+        let two: S = cast(2).unwrap();
+        let outer = self.slerp(other, amount);
+        let inner = a.slerp(b, amount);
+        outer.slerp(inner, two * amount * (S::one() - amount))
+    }
+
+    /// The control point at the unit quaternion `self` for `squad`, between
+    /// the keyframes `prev` and `next`.
+    ///
+    /// The neighbours should be in the same hemisphere as `self`, which can
+    /// be ensured by negating them when their dot product with `self` is
+    /// negative.
+    pub fn squad_control_point(self, prev: Quaternion<S>, next: Quaternion<S>) -> Quaternion<S> {
+        let inv = self.conjugate();
+        let quarter: S = cast(0.25f64).unwrap();
+        let tangent = ((inv * next).ln() + (inv * prev).ln()) * -quarter;
+        self * tangent.exp()
+    }
+
+    /// Evaluate a smooth curve through a sequence of unit quaternions with
+    /// `squad`, returning `None` if there are no keyframes.
+    ///
+    /// Keyframe `i` is reached at `time == i`, and `time` is clamped to the
+    /// range of the keyframes. The control points are computed from the
+    /// neighbouring keyframes, and the first and last keyframes are treated
+    /// as their own neighbours.
+    pub fn squad_spline(keyframes: &[Quaternion<S>], time: S) -> Option<Quaternion<S>> {
+        let (segment, t) = spline_segment(keyframes, time)?;
+        let [q0, q1, q2, q3] = segment;
+        let a = q1.squad_control_point(q0, q2);
+        let b = q2.squad_control_point(q1, q3);
+        Some(q1.squad(a, b, q2, t))
+    }
+
+    /// Evaluate a Catmull-Rom spline through a sequence of unit quaternions,
+    /// returning `None` if there are no keyframes.
+    ///
+    /// Keyframe `i` is reached at `time == i`, and `time` is clamped to the
+    /// range of the keyframes. The curve is evaluated with the pyramidal
+    /// formulation of Barry and Goldman, with `slerp` in place of linear
+    /// interpolation, and the first and last keyframes are treated as their
+    /// own neighbours.
+    pub fn catmull_rom_spline(keyframes: &[Quaternion<S>], time: S) -> Option<Quaternion<S>> {
+        let (segment, t) = spline_segment(keyframes, time)?;
+        let [q0, q1, q2, q3] = segment;
+        let half: S = cast(0.5f64).unwrap();
+        let a1 = q0.slerp(q1, t + S::one());
+        let a2 = q1.slerp(q2, t);
+        let a3 = q2.slerp(q3, t - S::one());
+        let b1 = a1.slerp(a2, (t + S::one()) * half);
+        let b2 = a2.slerp(a3, t * half);
+        Some(b1.slerp(b2, t).normalize())
+    }
+
+    /// Split the unit quaternion into a rotation around `axis`, the twist,
+    /// and a rotation around an axis perpendicular to it, the swing, so that
+    /// `self == swing * twist`. `axis` must be normalized.
+    ///
+    /// The twist angle is in the range `-π` to `π`. When the quaternion turns
+    /// a half turn around an axis perpendicular to `axis`, the twist is not
+    /// defined, and the whole rotation is returned as the swing.
+    pub fn swing_twist(self, axis: Vector3<S>) -> (Quaternion<S>, Quaternion<S>) {
+        let projected = axis * self.v.dot(axis);
+        let twist = Quaternion::from_sv(self.s, projected);
+        let magnitude2 = twist.magnitude2();
+        if magnitude2 <= S::default_epsilon() * S::default_epsilon() {
+            return (self, Quaternion::one());
+        }
+
+        let twist = if twist.s < S::zero() { -twist } else { twist } / magnitude2.sqrt();
+        (self * twist.conjugate(), twist)
+    }
+
+    /// Limit the twist of the unit quaternion around `axis` to the range
+    /// `min` to `max`, keeping its swing. `axis` must be normalized.
+    ///
+    /// See `swing_twist` for how the rotation is split.
+    pub fn clamp_twist<A: Into<Rad<S>>>(self, axis: Vector3<S>, min: A, max: A) -> Quaternion<S> {
+        let (swing, twist) = self.swing_twist(axis);
+        let two: S = cast(2).unwrap();
+        let angle = twist.v.dot(axis).atan2(twist.s) * two;
+        let clamped = angle.max(min.into().0).min(max.into().0);
+        swing * Quaternion::from_axis_angle(axis, Rad(clamped))
+    }
+
+    /// Limit the swing of the unit quaternion away from `axis` to a cone with
+    /// a half angle of `max`, keeping its twist. `axis` must be normalized.
+    ///
+    /// See `swing_twist` for how the rotation is split.
+    pub fn clamp_swing<A: Into<Rad<S>>>(self, axis: Vector3<S>, max: A) -> Quaternion<S> {
+        let (swing, twist) = self.swing_twist(axis);
+        let swing = if swing.s < S::zero() { -swing } else { swing };
+        let two: S = cast(2).unwrap();
+        let sin = swing.v.magnitude();
+        let angle = Rad(sin.atan2(swing.s) * two);
+        let max = max.into();
+        if angle <= max {
+            return self;
+        }
+        Quaternion::from_axis_angle(swing.v / sin, max) * twist
+    }
+}
+
+/// The four keyframes around `time` and the position of `time` between the
+/// middle two, with each keyframe moved into the same hemisphere as the one
+/// before it.
+fn spline_segment<S: BaseFloat>(
+    keyframes: &[Quaternion<S>],
+    time: S,
+) -> Option<([Quaternion<S>; 4], S)> {
+    let last = keyframes.len().checked_sub(1)?;
+    let time = time.max(S::zero()).min(cast(last).unwrap());
+    let i = time.floor().to_usize().unwrap().min(last.saturating_sub(1));
+    let t = time - cast(i).unwrap();
+
+    let key = |j: usize| keyframes[j.min(last)];
+    let mut segment = [key(i.saturating_sub(1)), key(i), key(i + 1), key(i + 2)];
+    if segment[0].dot(segment[1]) < S::zero() {
+        segment[0] = -segment[0];
+    }
+    for j in 2..4 {
+        if segment[j].dot(segment[j - 1]) < S::zero() {
+            segment[j] = -segment[j];
+        }
+    }
+    Some((segment, t))
+}
+
+/// `sin(x) / x`, given `sin(x)`, falling back to its Taylor series near zero
+/// where the division would be inaccurate.
+fn sinc<S: BaseFloat>(x: S, sin: S) -> S {
+    let x2 = x * x;
+    if x2 * x2 < S::epsilon() {
+        let six: S = cast(6).unwrap();
+        S::one() - x2 / six
+    } else {
+        sin / x
+    }
 }
 
 impl<S: BaseFloat> Zero for Quaternion<S> {
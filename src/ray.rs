@@ -0,0 +1,279 @@
+// Copyright 2019 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rays are half-lines starting at an origin point and extending infinitely in
+//! a given direction. They are mostly used for picking and visibility queries.
+
+use structure::*;
+
+use approx;
+use num::BaseFloat;
+use point::{Point2, Point3};
+use transform::Transform;
+use vector::{Vector2, Vector3};
+
+/// A ray in 2-dimensional space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ray2<S> {
+    /// The point the ray starts from.
+    pub origin: Point2<S>,
+    /// The direction the ray is heading in.
+    pub direction: Vector2<S>,
+}
+
+/// A ray in 3-dimensional space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ray3<S> {
+    /// The point the ray starts from.
+    pub origin: Point3<S>,
+    /// The direction the ray is heading in.
+    pub direction: Vector3<S>,
+}
+
+macro_rules! impl_ray {
+    ($RayN:ident, $PointN:ident, $VectorN:ident, $n:expr) => {
+        impl<S> $RayN<S> {
+            /// Construct a new ray from an origin point and a direction.
+            #[inline]
+            pub const fn new(origin: $PointN<S>, direction: $VectorN<S>) -> $RayN<S> {
+                $RayN {
+                    origin: origin,
+                    direction: direction,
+                }
+            }
+        }
+
+        impl<S: BaseFloat> $RayN<S> {
+            /// Returns the point at parameter `t` along the ray, that is
+            /// `origin + direction * t`.
+            ///
+            /// All intersection queries report their hit distances in terms of
+            /// this parameter, so they are only true euclidean distances if
+            /// the direction of the ray is normalized.
+            #[inline]
+            pub fn at(&self, t: S) -> $PointN<S> {
+                self.origin + self.direction * t
+            }
+
+            /// Returns a ray with the same origin, but with a normalized
+            /// direction.
+            #[inline]
+            pub fn normalize(&self) -> $RayN<S> {
+                $RayN::new(self.origin, self.direction.normalize())
+            }
+
+            /// Transform the ray using the given transformation, for example
+            /// to move it from world space into the local space of an object.
+            ///
+            /// The direction is not renormalized, so that hit distances found
+            /// in the transformed space are valid parameters of both rays.
+            #[inline]
+            pub fn transform<T: Transform<$PointN<S>>>(&self, transform: &T) -> $RayN<S> {
+                $RayN::new(
+                    transform.transform_point(self.origin),
+                    transform.transform_vector(self.direction),
+                )
+            }
+
+            /// Intersect the ray with a sphere, returning the distance to the
+            /// closest hit in front of the origin, and the point that was hit.
+            ///
+            /// If the origin is inside the sphere, the point where the ray
+            /// exits the sphere is returned.
+            pub fn intersect_sphere(
+                &self,
+                center: $PointN<S>,
+                radius: S,
+            ) -> Option<(S, $PointN<S>)> {
+                let oc = self.origin - center;
+                let a = self.direction.magnitude2();
+                if a == S::zero() {
+                    return None;
+                }
+                let b = oc.dot(self.direction);
+                let c = oc.magnitude2() - radius * radius;
+                let discriminant = b * b - a * c;
+                if discriminant < S::zero() {
+                    return None;
+                }
+                let root = discriminant.sqrt();
+                let near = (-b - root) / a;
+                let far = (-b + root) / a;
+                let t = if near >= S::zero() { near } else { far };
+                if t < S::zero() {
+                    None
+                } else {
+                    Some((t, self.at(t)))
+                }
+            }
+
+            /// Intersect the ray with the axis-aligned box spanned by the
+            /// corners `min` and `max`, using the slab method.
+            ///
+            /// If the origin is inside the box, the point where the ray exits
+            /// the box is returned.
+            pub fn intersect_aabb(
+                &self,
+                min: $PointN<S>,
+                max: $PointN<S>,
+            ) -> Option<(S, $PointN<S>)> {
+                let mut t_min = S::neg_infinity();
+                let mut t_max = S::infinity();
+                for i in 0..$n {
+                    let inv = S::one() / self.direction[i];
+                    let mut t0 = (min[i] - self.origin[i]) * inv;
+                    let mut t1 = (max[i] - self.origin[i]) * inv;
+                    if t0 > t1 {
+                        ::std::mem::swap(&mut t0, &mut t1);
+                    }
+                    // NaNs appear when the ray lies exactly on a slab
+                    // boundary, in which case the slab does not restrict `t`
+                    if t0 > t_min {
+                        t_min = t0;
+                    }
+                    if t1 < t_max {
+                        t_max = t1;
+                    }
+                }
+                if t_max < t_min || t_max < S::zero() {
+                    return None;
+                }
+                let t = if t_min >= S::zero() { t_min } else { t_max };
+                Some((t, self.at(t)))
+            }
+        }
+
+        impl<S: BaseFloat> approx::AbsDiffEq for $RayN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                $PointN::abs_diff_eq(&self.origin, &other.origin, epsilon)
+                    && $VectorN::abs_diff_eq(&self.direction, &other.direction, epsilon)
+            }
+        }
+
+        impl<S: BaseFloat> approx::RelativeEq for $RayN<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: S::Epsilon,
+                max_relative: S::Epsilon,
+            ) -> bool {
+                $PointN::relative_eq(&self.origin, &other.origin, epsilon, max_relative)
+                    && $VectorN::relative_eq(
+                        &self.direction,
+                        &other.direction,
+                        epsilon,
+                        max_relative,
+                    )
+            }
+        }
+
+        impl<S: BaseFloat> approx::UlpsEq for $RayN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                $PointN::ulps_eq(&self.origin, &other.origin, epsilon, max_ulps)
+                    && $VectorN::ulps_eq(&self.direction, &other.direction, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+impl_ray!(Ray2, Point2, Vector2, 2);
+impl_ray!(Ray3, Point3, Vector3, 3);
+
+impl<S: BaseFloat> Ray3<S> {
+    /// Intersect the ray with the plane of points `p` satisfying
+    /// `normal.dot(p) + distance = 0`.
+    ///
+    /// Returns `None` if the ray is parallel to the plane, or if the plane is
+    /// behind the origin of the ray.
+    pub fn intersect_plane(&self, normal: Vector3<S>, distance: S) -> Option<(S, Point3<S>)> {
+        let denom = normal.dot(self.direction);
+        // compared relative to the lengths of the vectors, so that the test
+        // does not depend on their scale
+        if denom.abs() <= S::default_epsilon() * normal.magnitude() * self.direction.magnitude() {
+            return None;
+        }
+        let t = -(self.origin.dot(normal) + distance) / denom;
+        if t < S::zero() {
+            None
+        } else {
+            Some((t, self.at(t)))
+        }
+    }
+
+    /// Intersect the ray with the triangle `(a, b, c)`, using the
+    /// [Möller–Trumbore algorithm](https://en.wikipedia.org/wiki/M%C3%B6ller%E2%80%93Trumbore_intersection_algorithm).
+    ///
+    /// Both sides of the triangle are considered, and degenerate triangles are
+    /// never hit.
+    pub fn intersect_triangle(
+        &self,
+        a: Point3<S>,
+        b: Point3<S>,
+        c: Point3<S>,
+    ) -> Option<(S, Point3<S>)> {
+        let edge1 = b - a;
+        let edge2 = c - a;
+        let p = self.direction.cross(edge2);
+        let det = edge1.dot(p);
+        // `det` is the volume spanned by the edges and the direction, so it
+        // is compared relative to their lengths to stay independent of scale
+        let scale = edge1.magnitude() * edge2.magnitude() * self.direction.magnitude();
+        if det.abs() <= S::default_epsilon() * scale {
+            return None;
+        }
+        let inv_det = S::one() / det;
+
+        let s = self.origin - a;
+        let u = s.dot(p) * inv_det;
+        if u < S::zero() || u > S::one() {
+            return None;
+        }
+
+        let q = s.cross(edge1);
+        let v = self.direction.dot(q) * inv_det;
+        if v < S::zero() || u + v > S::one() {
+            return None;
+        }
+
+        let t = edge2.dot(q) * inv_det;
+        if t < S::zero() {
+            None
+        } else {
+            Some((t, self.at(t)))
+        }
+    }
+}
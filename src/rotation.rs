@@ -22,7 +22,7 @@ use structure::*;
 use angle::Rad;
 use approx;
 use euler::Euler;
-use matrix::{Matrix2, Matrix3};
+use matrix::{Matrix2, Matrix3, Orthonormalization};
 use num::BaseFloat;
 use point::{Point2, Point3};
 use quaternion::Quaternion;
@@ -297,6 +297,25 @@ impl<S: BaseFloat> Basis3<S> {
             mat: quaternion.clone().into(),
         }
     }
+
+    /// Create a new rotation from a matrix that is expected to be a rotation,
+    /// repairing the small errors that build up after many multiplications.
+    ///
+    /// Returns `None` if the matrix is too far from a rotation to be repaired,
+    /// for example if it contains a scale or a reflection. Otherwise the
+    /// closest rotation is returned.
+    pub fn from_matrix(mat: Matrix3<S>) -> Option<Basis3<S>> {
+        let tolerance = S::epsilon().sqrt();
+        let error = mat.transpose() * mat - Matrix3::identity();
+        let orthonormal = (0..3).all(|i| (0..3).all(|j| error[i][j].abs() <= tolerance));
+        if orthonormal && mat.determinant() > S::zero() {
+            Some(Basis3 {
+                mat: mat.orthonormalize(Orthonormalization::NearestRotation),
+            })
+        } else {
+            None
+        }
+    }
 }
 
 impl<S> AsRef<Matrix3<S>> for Basis3<S> {
@@ -0,0 +1,287 @@
+// Copyright 2019 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_traits::cast;
+
+use structure::*;
+
+use approx;
+use num::BaseFloat;
+use point::Point3;
+use ray::Ray3;
+use rotation::Rotation3;
+use transform::{Decomposed, Transform};
+use vector::Vector3;
+
+/// A sphere in 3-dimensional space, most commonly used as a bounding volume.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Sphere<S> {
+    pub center: Point3<S>,
+    pub radius: S,
+}
+
+impl<S> Sphere<S> {
+    /// Construct a new sphere from its center and radius.
+    #[inline]
+    pub const fn new(center: Point3<S>, radius: S) -> Sphere<S> {
+        Sphere {
+            center: center,
+            radius: radius,
+        }
+    }
+}
+
+impl<S: BaseFloat> Sphere<S> {
+    /// Construct the smallest sphere containing all of the points in the
+    /// slice, returning `None` if the slice is empty.
+    ///
+    /// This uses the move-to-front variant of
+    /// [Welzl's algorithm](https://en.wikipedia.org/wiki/Smallest-circle_problem#Welzl's_algorithm)
+    /// described by Bernd Gärtner in
+    /// [Fast and Robust Smallest Enclosing Balls](https://people.inf.ethz.ch/gaertner/subdir/texts/own_work/esa99_final.pdf).
+    pub fn from_points(points: &[Point3<S>]) -> Option<Sphere<S>> {
+        if points.is_empty() {
+            return None;
+        }
+        let mut points = points.to_vec();
+        let len = points.len();
+        let mut support = Vec::with_capacity(4);
+        Some(welzl(&mut points, len, &mut support))
+    }
+
+    /// Construct a sphere containing all of the points in the slice, returning
+    /// `None` if the slice is empty.
+    ///
+    /// This uses [Ritter's algorithm](https://en.wikipedia.org/wiki/Bounding_sphere#Ritter's_bounding_sphere),
+    /// which is much cheaper than `Sphere::from_points`, but usually produces
+    /// a sphere that is a few percent larger than necessary.
+    pub fn from_points_approx(points: &[Point3<S>]) -> Option<Sphere<S>> {
+        if points.is_empty() {
+            return None;
+        }
+        let farthest_from = |p: Point3<S>| {
+            points.iter().cloned().fold(p, |best, q| {
+                if p.distance2(q) > p.distance2(best) {
+                    q
+                } else {
+                    best
+                }
+            })
+        };
+        // start from the point furthest from the centroid, which is more
+        // likely to lie on the final sphere than an arbitrary one
+        let a = farthest_from(Point3::centroid(points));
+        let b = farthest_from(a);
+        let init = Sphere::new(a.midpoint(b), a.distance(b) / cast(2).unwrap());
+        Some(points.iter().fold(init, |sphere, &p| sphere.grow(p)))
+    }
+
+    /// Returns the smallest sphere containing both this sphere and `point`.
+    #[inline]
+    pub fn grow(&self, point: Point3<S>) -> Sphere<S> {
+        self.merge(&Sphere::new(point, S::zero()))
+    }
+
+    /// Returns the smallest sphere containing both this sphere and `other`.
+    pub fn merge(&self, other: &Sphere<S>) -> Sphere<S> {
+        let offset = other.center - self.center;
+        let distance = offset.magnitude();
+        if distance + other.radius <= self.radius {
+            return *self;
+        }
+        if distance + self.radius <= other.radius {
+            return *other;
+        }
+        let radius = (distance + self.radius + other.radius) / cast(2).unwrap();
+        let center = self.center + offset * ((radius - self.radius) / distance);
+        Sphere::new(center, radius)
+    }
+
+    /// Returns `true` if the point lies inside the sphere or on its surface.
+    #[inline]
+    pub fn contains_point(&self, point: Point3<S>) -> bool {
+        self.center.distance2(point) <= self.radius * self.radius
+    }
+
+    /// Returns `true` if `other` lies entirely inside this sphere.
+    #[inline]
+    pub fn contains(&self, other: &Sphere<S>) -> bool {
+        self.center.distance(other.center) + other.radius <= self.radius
+    }
+
+    /// Returns `true` if this sphere and `other` overlap. Spheres that only
+    /// touch are considered to be overlapping.
+    #[inline]
+    pub fn intersects(&self, other: &Sphere<S>) -> bool {
+        let radii = self.radius + other.radius;
+        self.center.distance2(other.center) <= radii * radii
+    }
+
+    /// Transform the sphere, scaling its radius by the magnitude of the scale
+    /// factor of the transformation.
+    #[inline]
+    pub fn transform<R: Rotation3<S>>(&self, transform: &Decomposed<Vector3<S>, R>) -> Sphere<S> {
+        Sphere::new(
+            transform.transform_point(self.center),
+            self.radius * transform.scale.abs(),
+        )
+    }
+
+    /// Intersect a ray with the sphere, returning the distance to the first
+    /// hit and the point that was hit.
+    ///
+    /// See `intersect_sphere` on the ray type for details.
+    #[inline]
+    pub fn intersect_ray(&self, ray: &Ray3<S>) -> Option<(S, Point3<S>)> {
+        ray.intersect_sphere(self.center, self.radius)
+    }
+}
+
+/// Containment test used while building spheres, which tolerates the rounding
+/// errors of the circumsphere computations.
+fn nearly_contains<S: BaseFloat>(sphere: &Sphere<S>, point: Point3<S>) -> bool {
+    if sphere.radius < S::zero() {
+        return false;
+    }
+    let tolerance = S::default_epsilon() * cast(64).unwrap();
+    let r2 = sphere.radius * sphere.radius;
+    sphere.center.distance2(point) <= r2 + r2 * tolerance
+}
+
+/// The smallest sphere containing `points[..end]` with all points of `support`
+/// on its surface. Points that end up on the surface are moved to the front of
+/// `points` so that later iterations find them early.
+fn welzl<S: BaseFloat>(
+    points: &mut [Point3<S>],
+    end: usize,
+    support: &mut Vec<Point3<S>>,
+) -> Sphere<S> {
+    let mut sphere = circumsphere(support);
+    if support.len() == 4 {
+        return sphere;
+    }
+    for i in 0..end {
+        let p = points[i];
+        if !nearly_contains(&sphere, p) {
+            support.push(p);
+            sphere = welzl(points, i, support);
+            support.pop();
+            points[..i + 1].rotate_right(1);
+        }
+    }
+    sphere
+}
+
+/// The smallest sphere with all of the (at most four) points on its surface.
+///
+/// Degenerate configurations, such as collinear or coplanar points, fall back
+/// to the sphere through a subset of the points.
+fn circumsphere<S: BaseFloat>(points: &[Point3<S>]) -> Sphere<S> {
+    let two: S = cast(2).unwrap();
+    match points.len() {
+        // an empty sphere that contains nothing
+        0 => Sphere::new(Point3::origin(), -S::one()),
+        1 => Sphere::new(points[0], S::zero()),
+        2 => Sphere::new(
+            points[0].midpoint(points[1]),
+            points[0].distance(points[1]) / two,
+        ),
+        3 => {
+            let a = points[0];
+            let ab = points[1] - a;
+            let ac = points[2] - a;
+            let n = ab.cross(ac);
+            // compared to the squared edge lengths, so that the test does not
+            // depend on the size of the triangle
+            if n.magnitude2() <= S::default_epsilon() * ab.magnitude2() * ac.magnitude2() {
+                // collinear, so use the two points furthest apart
+                let pairs = [(0, 1), (0, 2), (1, 2)];
+                return pairs
+                    .iter()
+                    .map(|&(i, j)| circumsphere(&[points[i], points[j]]))
+                    .fold(Sphere::new(a, S::zero()), |best, s| {
+                        if s.radius > best.radius {
+                            s
+                        } else {
+                            best
+                        }
+                    });
+            }
+            let offset = (n.cross(ab) * ac.magnitude2() + ac.cross(n) * ab.magnitude2())
+                / (two * n.magnitude2());
+            Sphere::new(a + offset, offset.magnitude())
+        }
+        _ => {
+            let a = points[0];
+            let u = points[1] - a;
+            let v = points[2] - a;
+            let w = points[3] - a;
+            let volume = u.dot(v.cross(w));
+            let scale = u.magnitude() * v.magnitude() * w.magnitude();
+            if volume.abs() <= S::default_epsilon() * scale {
+                // coplanar, so the last point lies on the circle through the
+                // first three
+                return circumsphere(&points[..3]);
+            }
+            let offset = (v.cross(w) * u.magnitude2()
+                + w.cross(u) * v.magnitude2()
+                + u.cross(v) * w.magnitude2())
+                / (two * volume);
+            Sphere::new(a + offset, offset.magnitude())
+        }
+    }
+}
+
+impl<S: BaseFloat> approx::AbsDiffEq for Sphere<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Point3::abs_diff_eq(&self.center, &other.center, epsilon)
+            && S::abs_diff_eq(&self.radius, &other.radius, epsilon)
+    }
+}
+
+impl<S: BaseFloat> approx::RelativeEq for Sphere<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Point3::relative_eq(&self.center, &other.center, epsilon, max_relative)
+            && S::relative_eq(&self.radius, &other.radius, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> approx::UlpsEq for Sphere<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Point3::ulps_eq(&self.center, &other.center, epsilon, max_ulps)
+            && S::ulps_eq(&self.radius, &other.radius, epsilon, max_ulps)
+    }
+}
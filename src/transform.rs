@@ -13,14 +13,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use num_traits::cast;
+
 use structure::*;
 
 use approx;
 use matrix::{Matrix2, Matrix3, Matrix4};
 use num::{BaseFloat, BaseNum};
 use point::{Point2, Point3};
+use quaternion::Quaternion;
 use rotation::*;
-use vector::{Vector2, Vector3};
+use vector::{Vector2, Vector3, Vector4};
 
 /// A trait representing an [affine
 /// transformation](https://en.wikipedia.org/wiki/Affine_transformation) that
@@ -138,6 +141,77 @@ where
     }
 }
 
+impl<S: BaseFloat, V: VectorSpace<Scalar = S>> Decomposed<V, Quaternion<S>> {
+    /// Interpolate between this transform and `other`, by `amount`.
+    ///
+    /// The scale and displacement are interpolated linearly, and the rotation
+    /// with `Quaternion::slerp`, taking the shortest path between the two
+    /// orientations.
+    pub fn interpolate(&self, other: &Self, amount: S) -> Self {
+        let rot = if self.rot.dot(other.rot) < S::zero() {
+            -other.rot
+        } else {
+            other.rot
+        };
+        Decomposed {
+            scale: self.scale + (other.scale - self.scale) * amount,
+            rot: self.rot.slerp(rot, amount),
+            disp: self.disp.lerp(other.disp, amount),
+        }
+    }
+
+    /// Blend any number of transforms together, each with a weight, such as
+    /// the poses of several animations playing at the same time. Returns
+    /// `None` if there are no transforms, or if the weights add up to zero.
+    ///
+    /// The scales and displacements are averaged using the weights. The
+    /// rotations are blended by generalizing `Quaternion::nlerp` to several
+    /// quaternions. Each quaternion is first flipped into the hemisphere of
+    /// the one with the largest weight, or the first of those if several
+    /// share it, so that the result does not depend on the sign of the
+    /// quaternions. Blending two transforms with the weights `1 - amount` and
+    /// `amount` gives the same rotation as interpolating them with `nlerp`
+    /// along the shortest path.
+    pub fn blend(weighted: &[(Self, S)]) -> Option<Self> {
+        let (mut reference, mut max_weight) = match weighted.first() {
+            Some(&(t, weight)) => (t.rot, weight),
+            None => return None,
+        };
+        for &(t, weight) in &weighted[1..] {
+            if weight > max_weight {
+                reference = t.rot;
+                max_weight = weight;
+            }
+        }
+
+        let mut total = S::zero();
+        let mut scale = S::zero();
+        let mut rot = Quaternion::zero();
+        let mut disp = V::zero();
+        for &(t, weight) in weighted {
+            let sign = if t.rot.dot(reference) < S::zero() {
+                -S::one()
+            } else {
+                S::one()
+            };
+            total += weight;
+            scale += t.scale * weight;
+            rot += t.rot * (weight * sign);
+            disp = disp + t.disp * weight;
+        }
+
+        if total == S::zero() {
+            None
+        } else {
+            Some(Decomposed {
+                scale: scale / total,
+                rot: rot.normalize(),
+                disp: disp / total,
+            })
+        }
+    }
+}
+
 pub trait Transform2<S: BaseNum>: Transform<Point2<S>> + Into<Matrix3<S>> {}
 pub trait Transform3<S: BaseNum>: Transform<Point3<S>> + Into<Matrix4<S>> {}
 
@@ -159,6 +233,181 @@ impl<S: BaseFloat, R: Rotation3<S>> From<Decomposed<Vector3<S>, R>> for Matrix4<
     }
 }
 
+/// The parts of a `Matrix4`, as found by `Matrix4::decompose`.
+///
+/// The matrix is rebuilt by applying the `scale`, the `shear`, the `rotation`,
+/// the `translation` and finally the `perspective`, in that order.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MatrixDecomposition<S> {
+    /// The scale along each of the local axes. If `reflection` is set, all
+    /// three components are negative.
+    pub scale: Vector3<S>,
+    /// The shear factors `xy`, `xz` and `yz`, which add the `y` coordinate
+    /// times `shear.x` to the `x` coordinate, the `z` coordinate times
+    /// `shear.y` to the `x` coordinate, and the `z` coordinate times `shear.z`
+    /// to the `y` coordinate.
+    pub shear: Vector3<S>,
+    /// The rotation, found from the orthonormalized columns of the matrix. If
+    /// `reflection` is set, those axes are negated first, so that they form
+    /// a proper rotation.
+    pub rotation: Quaternion<S>,
+    /// The translation, taken from the last column of the matrix.
+    pub translation: Vector3<S>,
+    /// The bottom row of the matrix that remains after taking out the affine
+    /// part. This is `(0, 0, 0, 1)` if the matrix has no perspective.
+    pub perspective: Vector4<S>,
+    /// Whether the matrix mirrors space, which is represented by negating all
+    /// three components of `scale`.
+    pub reflection: bool,
+}
+
+impl<S: BaseFloat> Matrix4<S> {
+    /// Split the matrix into its translation, rotation, scale, shear and
+    /// perspective parts, following `unmatrix` by Spencer W. Thomas from
+    /// _Graphics Gems II_.
+    ///
+    /// Returns `None` if the matrix is singular, or if its bottom right
+    /// element is zero, as is the case for perspective projection matrices.
+    pub fn decompose(&self) -> Option<MatrixDecomposition<S>> {
+        if self.w.w == S::zero() {
+            return None;
+        }
+        let m = *self / self.w.w;
+        let mut affine = m;
+        affine.x.w = S::zero();
+        affine.y.w = S::zero();
+        affine.z.w = S::zero();
+        let perspective = affine.invert()?.transpose() * m.row(3);
+
+        // orthonormalize the columns with Gram-Schmidt, keeping track of the
+        // amount of each earlier column that was removed as the shear
+        let mut x = m.x.truncate();
+        let mut y = m.y.truncate();
+        let mut z = m.z.truncate();
+        let mut scale = Vector3::zero();
+        let mut shear = Vector3::zero();
+
+        scale.x = x.magnitude();
+        x /= scale.x;
+        shear.x = x.dot(y);
+        y -= x * shear.x;
+        scale.y = y.magnitude();
+        y /= scale.y;
+        shear.x /= scale.y;
+
+        shear.y = x.dot(z);
+        z -= x * shear.y;
+        shear.z = y.dot(z);
+        z -= y * shear.z;
+        scale.z = z.magnitude();
+        z /= scale.z;
+        shear.y /= scale.z;
+        shear.z /= scale.z;
+
+        let reflection = x.dot(y.cross(z)) < S::zero();
+        if reflection {
+            scale = -scale;
+            x = -x;
+            y = -y;
+            z = -z;
+        }
+
+        Some(MatrixDecomposition {
+            scale: scale,
+            shear: shear,
+            rotation: Quaternion::from(Matrix3::from_cols(x, y, z)).normalize(),
+            translation: m.w.truncate(),
+            perspective: perspective,
+            reflection: reflection,
+        })
+    }
+}
+
+impl<S: BaseFloat> MatrixDecomposition<S> {
+    /// Convert the parts into a `Decomposed` transform, returning `None` if
+    /// they contain anything other than a translation, a rotation and a
+    /// uniform scale, up to rounding errors.
+    ///
+    /// A reflection is kept as a negative scale.
+    pub fn to_decomposed(&self) -> Option<Decomposed<Vector3<S>, Quaternion<S>>> {
+        let s = self.scale;
+        let max = s.x.abs().max(s.y.abs()).max(s.z.abs());
+        let tolerance = Self::tolerance();
+        let uniform = (s.x - s.y).abs() <= max * tolerance && (s.x - s.z).abs() <= max * tolerance;
+        if uniform && self.is_affine_trs() {
+            Some(Decomposed {
+                scale: s.x,
+                rot: self.rotation,
+                disp: self.translation,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Convert the parts into a `DecomposedNonUniform` transform, returning
+    /// `None` if they contain a shear or a perspective part, up to rounding
+    /// errors.
+    ///
+    /// A reflection is kept as a negative scale.
+    pub fn to_decomposed_non_uniform(
+        &self,
+    ) -> Option<DecomposedNonUniform<Vector3<S>, Quaternion<S>>> {
+        if self.is_affine_trs() {
+            Some(DecomposedNonUniform {
+                scale: self.scale,
+                rot: self.rotation,
+                disp: self.translation,
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn tolerance() -> S {
+        S::default_epsilon() * cast(64).unwrap()
+    }
+
+    /// Whether the shear and perspective parts are negligible.
+    fn is_affine_trs(&self) -> bool {
+        let tolerance = Self::tolerance();
+        let k = self.shear;
+        let p = self.perspective;
+        k.x.abs() <= tolerance
+            && k.y.abs() <= tolerance
+            && k.z.abs() <= tolerance
+            && p.x.abs() <= tolerance
+            && p.y.abs() <= tolerance
+            && p.z.abs() <= tolerance
+            && (p.w - S::one()).abs() <= tolerance
+    }
+}
+
+impl<S: BaseFloat> From<MatrixDecomposition<S>> for Matrix4<S> {
+    fn from(parts: MatrixDecomposition<S>) -> Matrix4<S> {
+        let k = parts.shear;
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let shear = Matrix3::new(
+            S::one(), S::zero(), S::zero(),
+            k.x, S::one(), S::zero(),
+            k.y, k.z, S::one(),
+        );
+        let m = Matrix3::from(parts.rotation) * shear * Matrix3::from_diagonal(parts.scale);
+        let mut m = Matrix4::from(m);
+        m.w = parts.translation.extend(S::one());
+
+        let p = parts.perspective;
+        let mut perspective = Matrix4::identity();
+        perspective.x.w = p.x;
+        perspective.y.w = p.y;
+        perspective.z.w = p.z;
+        perspective.w.w = p.w;
+        perspective * m
+    }
+}
+
 impl<S: BaseFloat, R: Rotation2<S>> Transform2<S> for Decomposed<Vector2<S>, R> {}
 
 impl<S: BaseFloat, R: Rotation3<S>> Transform3<S> for Decomposed<Vector3<S>, R> {}
@@ -222,6 +471,210 @@ where
     }
 }
 
+/// A transformation consisting of a rotation, a displacement vector and a
+/// separate scale factor along each axis.
+///
+/// Points are first scaled along the axes of the local space, then rotated
+/// and finally displaced.
+///
+/// The product of two such transformations is only another one of them if the
+/// scale of the first commutes with the rotation of the second. This is the
+/// case when the scale is uniform, when the rotation is the identity, or more
+/// generally when the rotation only mixes axes that share the same scale
+/// factor. In every other case the product contains a shear, which this type
+/// cannot represent. `concat` and `inverse_transform` then compose the scales
+/// and the rotations separately, which drops the shear but is not the closest
+/// transformation to the exact result in general. Use `Matrix4` if the shear
+/// needs to be kept.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DecomposedNonUniform<V, R> {
+    pub scale: V,
+    pub rot: R,
+    pub disp: V,
+}
+
+impl<P: EuclideanSpace, R: Rotation<P>> Transform<P> for DecomposedNonUniform<P::Diff, R>
+where
+    P::Scalar: BaseFloat,
+    P::Diff: VectorSpace + Array<Element = P::Scalar> + ElementWise,
+{
+    #[inline]
+    fn one() -> DecomposedNonUniform<P::Diff, R> {
+        DecomposedNonUniform {
+            scale: P::Diff::from_value(P::Scalar::one()),
+            rot: R::one(),
+            disp: P::Diff::zero(),
+        }
+    }
+
+    #[inline]
+    fn look_at(eye: P, center: P, up: P::Diff) -> DecomposedNonUniform<P::Diff, R> {
+        let rot = R::look_at(center - eye, up);
+        let disp = rot.rotate_vector(P::origin() - eye);
+        DecomposedNonUniform {
+            scale: P::Diff::from_value(P::Scalar::one()),
+            rot: rot,
+            disp: disp,
+        }
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: P::Diff) -> P::Diff {
+        self.rot.rotate_vector(vec.mul_element_wise(self.scale))
+    }
+
+    /// Inverse transform a vector, by undoing the rotation and then the
+    /// scale. Unlike `inverse_transform`, this is always exact.
+    #[inline]
+    fn inverse_transform_vector(&self, vec: P::Diff) -> Option<P::Diff> {
+        if self.has_zero_scale() {
+            None
+        } else {
+            Some(
+                self.rot
+                    .invert()
+                    .rotate_vector(vec)
+                    .div_element_wise(self.scale),
+            )
+        }
+    }
+
+    #[inline]
+    fn transform_point(&self, point: P) -> P {
+        P::from_vec(self.transform_vector(point.to_vec()) + self.disp)
+    }
+
+    /// Combine this transform with another, returning a transformation that
+    /// applies `other` first.
+    ///
+    /// The result multiplies the scales and the rotations separately, so its
+    /// linear part is `self.rot * other.rot * (self.scale * other.scale)`
+    /// instead of `self.rot * self.scale * other.rot * other.scale`. The two
+    /// agree whenever `self.scale` commutes with `other.rot`. The displacement
+    /// is always exact, so the origin of `other` is mapped correctly.
+    fn concat(&self, other: &DecomposedNonUniform<P::Diff, R>) -> DecomposedNonUniform<P::Diff, R> {
+        DecomposedNonUniform {
+            scale: self.scale.mul_element_wise(other.scale),
+            rot: self.rot * other.rot,
+            disp: self.transform_vector(other.disp) + self.disp,
+        }
+    }
+
+    /// Create a transform that undoes this one, returning `None` if any of
+    /// the scale factors is zero.
+    ///
+    /// The exact inverse undoes the rotation before the scale, while this type
+    /// always scales first, so the result uses the inverted rotation and the
+    /// reciprocal scale in the usual order. The two agree whenever the scale
+    /// commutes with the rotation. The displacement is chosen so that the
+    /// result always maps `self.disp` back to the origin.
+    fn inverse_transform(&self) -> Option<DecomposedNonUniform<P::Diff, R>> {
+        if self.has_zero_scale() {
+            None
+        } else {
+            let scale = P::Diff::from_value(P::Scalar::one()).div_element_wise(self.scale);
+            let rot = self.rot.invert();
+            let disp = rot.rotate_vector(self.disp.mul_element_wise(scale)) * -P::Scalar::one();
+            Some(DecomposedNonUniform {
+                scale: scale,
+                rot: rot,
+                disp: disp,
+            })
+        }
+    }
+}
+
+impl<V: Array, R> DecomposedNonUniform<V, R>
+where
+    V::Element: BaseFloat,
+{
+    #[inline]
+    fn has_zero_scale(&self) -> bool {
+        (0..V::len()).any(|i| ulps_eq!(self.scale[i], &V::Element::zero()))
+    }
+}
+
+impl<S: BaseFloat, R: Rotation2<S>> From<DecomposedNonUniform<Vector2<S>, R>> for Matrix3<S> {
+    fn from(dec: DecomposedNonUniform<Vector2<S>, R>) -> Matrix3<S> {
+        let m: Matrix2<_> = dec.rot.into();
+        let m = m * Matrix2::from_diagonal(dec.scale);
+        let mut m: Matrix3<_> = m.into();
+        m.z = dec.disp.extend(S::one());
+        m
+    }
+}
+
+impl<S: BaseFloat, R: Rotation3<S>> From<DecomposedNonUniform<Vector3<S>, R>> for Matrix4<S> {
+    fn from(dec: DecomposedNonUniform<Vector3<S>, R>) -> Matrix4<S> {
+        let m: Matrix3<_> = dec.rot.into();
+        let m = m * Matrix3::from_diagonal(dec.scale);
+        let mut m: Matrix4<_> = m.into();
+        m.w = dec.disp.extend(S::one());
+        m
+    }
+}
+
+impl<S: BaseFloat, R: Rotation2<S>> Transform2<S> for DecomposedNonUniform<Vector2<S>, R> {}
+
+impl<S: BaseFloat, R: Rotation3<S>> Transform3<S> for DecomposedNonUniform<Vector3<S>, R> {}
+
+impl<V, R, E: BaseFloat> approx::AbsDiffEq for DecomposedNonUniform<V, R>
+where
+    V: approx::AbsDiffEq<Epsilon = E>,
+    R: approx::AbsDiffEq<Epsilon = E>,
+{
+    type Epsilon = E;
+
+    #[inline]
+    fn default_epsilon() -> E {
+        E::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: E) -> bool {
+        V::abs_diff_eq(&self.scale, &other.scale, epsilon)
+            && R::abs_diff_eq(&self.rot, &other.rot, epsilon)
+            && V::abs_diff_eq(&self.disp, &other.disp, epsilon)
+    }
+}
+
+impl<V, R, E: BaseFloat> approx::RelativeEq for DecomposedNonUniform<V, R>
+where
+    V: approx::RelativeEq<Epsilon = E>,
+    R: approx::RelativeEq<Epsilon = E>,
+{
+    #[inline]
+    fn default_max_relative() -> E {
+        E::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: E, max_relative: E) -> bool {
+        V::relative_eq(&self.scale, &other.scale, epsilon, max_relative)
+            && R::relative_eq(&self.rot, &other.rot, epsilon, max_relative)
+            && V::relative_eq(&self.disp, &other.disp, epsilon, max_relative)
+    }
+}
+
+impl<V, R, E: BaseFloat> approx::UlpsEq for DecomposedNonUniform<V, R>
+where
+    V: approx::UlpsEq<Epsilon = E>,
+    R: approx::UlpsEq<Epsilon = E>,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        E::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: E, max_ulps: u32) -> bool {
+        V::ulps_eq(&self.scale, &other.scale, epsilon, max_ulps)
+            && R::ulps_eq(&self.rot, &other.rot, epsilon, max_ulps)
+            && V::ulps_eq(&self.disp, &other.disp, epsilon, max_ulps)
+    }
+}
+
 #[cfg(feature = "serde")]
 #[doc(hidden)]
 mod serde_ser {
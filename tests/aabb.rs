@@ -0,0 +1,133 @@
+// Copyright 2019 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn unit_cube() -> Aabb3<f64> {
+    Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0))
+}
+
+#[test]
+fn test_new_sorts_corners() {
+    let aabb = Aabb2::new(Point2::new(3.0f64, -1.0), Point2::new(1.0, 2.0));
+    assert_eq!(aabb.min, Point2::new(1.0, -1.0));
+    assert_eq!(aabb.max, Point2::new(3.0, 2.0));
+}
+
+#[test]
+fn test_from_points() {
+    let points = [
+        Point3::new(1.0f64, 0.0, 3.0),
+        Point3::new(-2.0, 5.0, 1.0),
+        Point3::new(0.0, -1.0, 2.0),
+    ];
+    let aabb = Aabb3::from_points(&points).unwrap();
+    assert_eq!(aabb.min, Point3::new(-2.0, -1.0, 1.0));
+    assert_eq!(aabb.max, Point3::new(1.0, 5.0, 3.0));
+    assert!(points.iter().all(|&p| aabb.contains_point(p)));
+
+    assert!(Aabb3::<f64>::from_points(&[]).is_none());
+}
+
+#[test]
+fn test_union_intersection() {
+    let a = Aabb2::new(Point2::new(0.0f64, 0.0), Point2::new(2.0, 2.0));
+    let b = Aabb2::new(Point2::new(1.0f64, 1.0), Point2::new(3.0, 4.0));
+    let c = Aabb2::new(Point2::new(5.0f64, 5.0), Point2::new(6.0, 6.0));
+
+    let u = a.union(&b);
+    assert_eq!(u, Aabb2::new(Point2::new(0.0, 0.0), Point2::new(3.0, 4.0)));
+    assert!(u.contains(&a) && u.contains(&b));
+
+    let i = a.intersection(&b).unwrap();
+    assert_eq!(i, Aabb2::new(Point2::new(1.0, 1.0), Point2::new(2.0, 2.0)));
+    assert!(a.intersects(&b));
+
+    assert!(a.intersection(&c).is_none());
+    assert!(!a.intersects(&c));
+}
+
+#[test]
+fn test_contains_point() {
+    let aabb = unit_cube();
+    assert!(aabb.contains_point(Point3::new(0.0, 0.0, 0.0)));
+    assert!(aabb.contains_point(Point3::new(1.0, -1.0, 1.0)));
+    assert!(!aabb.contains_point(Point3::new(0.0, 1.5, 0.0)));
+}
+
+#[test]
+fn test_measures() {
+    let aabb = Aabb3::new(Point3::new(1.0f64, 2.0, 3.0), Point3::new(3.0, 5.0, 7.0));
+    assert_eq!(aabb.center(), Point3::new(2.0, 3.5, 5.0));
+    assert_eq!(aabb.extents(), Vector3::new(2.0, 3.0, 4.0));
+    assert_eq!(aabb.volume(), 24.0);
+    assert_eq!(aabb.surface_area(), 52.0);
+
+    let rect = Aabb2::new(Point2::new(0.0f64, 0.0), Point2::new(2.0, 3.0));
+    assert_eq!(rect.volume(), 6.0);
+    assert_eq!(rect.surface_area(), 10.0);
+}
+
+#[test]
+fn test_closest_point() {
+    let aabb = unit_cube();
+    assert_eq!(
+        aabb.closest_point(Point3::new(3.0, 0.5, -4.0)),
+        Point3::new(1.0, 0.5, -1.0)
+    );
+}
+
+#[test]
+fn test_transform_matrix() {
+    let aabb = Aabb3::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(2.0, 1.0, 1.0));
+    let m = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+        * Matrix4::from_nonuniform_scale(2.0, -1.0, 1.0);
+    let expected = Aabb3::new(Point3::new(1.0, 1.0, 3.0), Point3::new(5.0, 2.0, 4.0));
+    assert_ulps_eq!(aabb.transform(&m), expected);
+}
+
+#[test]
+fn test_transform_is_conservative() {
+    let aabb = Aabb3::new(Point3::new(-1.0f64, -2.0, 0.5), Point3::new(3.0, 1.0, 2.0));
+    let t = Decomposed {
+        scale: 1.5f64,
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalize(), Deg(30.0)),
+        disp: Vector3::new(-4.0, 0.0, 2.0),
+    };
+    let transformed = aabb.transform(&t);
+
+    // for affine transforms Arvo's method is exact, so the box is also tight
+    let corners: Vec<_> = aabb
+        .corners()
+        .iter()
+        .map(|&c| t.transform_point(c))
+        .collect();
+    let tight = Aabb3::from_points(&corners).unwrap();
+    assert_relative_eq!(transformed, tight, epsilon = 1e-12);
+
+    let m: Matrix4<f64> = t.into();
+    assert_relative_eq!(aabb.transform(&m), transformed, epsilon = 1e-12);
+}
+
+#[test]
+fn test_intersect_ray() {
+    let ray = Ray3::new(Point3::new(0.0f64, 0.0, 5.0), -Vector3::unit_z());
+    let (t, p) = unit_cube().intersect_ray(&ray).unwrap();
+    assert_ulps_eq!(t, 4.0);
+    assert_ulps_eq!(p, Point3::new(0.0, 0.0, 1.0));
+}
@@ -0,0 +1,260 @@
+// Copyright 2019 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "rand")]
+
+extern crate approx;
+extern crate cgmath;
+extern crate rand;
+
+use cgmath::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const SAMPLES: usize = 20000;
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(0x5eed)
+}
+
+#[test]
+fn test_uniform_rotation_is_unit() {
+    let mut rng = rng();
+    for _ in 0..100 {
+        let q: Quaternion<f64> = rng.sample(UniformRotation);
+        assert_relative_eq!(q.magnitude(), 1.0, epsilon = 1e-12);
+
+        let b: Basis3<f64> = rng.sample(UniformRotation);
+        let m = *b.as_ref();
+        assert_relative_eq!(m * m.transpose(), Matrix3::identity(), epsilon = 1e-12);
+        assert_relative_eq!(m.determinant(), 1.0, epsilon = 1e-12);
+
+        let m: Matrix3<f32> = rng.sample(UniformRotation);
+        assert_relative_eq!(m.determinant(), 1.0, epsilon = 1e-5);
+    }
+}
+
+#[test]
+fn test_uniform_rotation_moments() {
+    // for rotations that are uniform over all orientations, each component
+    // of the quaternion has a mean of zero and a mean square of a quarter,
+    // and rotated vectors are uniform on the sphere
+    let mut rng = rng();
+    let mut mean = Vector4::zero();
+    let mut square = Vector4::zero();
+    let mut rotated = Vector3::zero();
+    let mut rotated_square = Vector3::zero();
+    for _ in 0..SAMPLES {
+        let q: Quaternion<f64> = rng.sample(UniformRotation);
+        let v = Vector4::new(q.s, q.v.x, q.v.y, q.v.z);
+        mean += v;
+        square += v.mul_element_wise(v);
+        let r = q.rotate_vector(Vector3::unit_z());
+        rotated += r;
+        rotated_square += r.mul_element_wise(r);
+    }
+    let n = SAMPLES as f64;
+    assert_relative_eq!(mean / n, Vector4::zero(), epsilon = 0.02);
+    assert_relative_eq!(square / n, Vector4::from_value(0.25), epsilon = 0.01);
+    assert_relative_eq!(rotated / n, Vector3::zero(), epsilon = 0.02);
+    assert_relative_eq!(
+        rotated_square / n,
+        Vector3::from_value(1.0 / 3.0),
+        epsilon = 0.01
+    );
+}
+
+#[test]
+fn test_uniform_rotation_angles() {
+    // the rotation angle of a uniform rotation has the density
+    // `(1 - cos(angle)) / π`, so the fraction of angles below `limit` is
+    // `(limit - sin(limit)) / π`
+    let mut rng = rng();
+    let limit = 2.0 * std::f64::consts::PI / 3.0;
+    let below = (0..SAMPLES)
+        .filter(|_| {
+            let q: Quaternion<f64> = rng.sample(UniformRotation);
+            2.0 * q.s.abs().acos() < limit
+        })
+        .count();
+    let expected = (limit - limit.sin()) / std::f64::consts::PI;
+    assert_relative_eq!(below as f64 / SAMPLES as f64, expected, epsilon = 0.01);
+}
+
+/// The mean of `f` over samples of `distribution`.
+fn mean<D, T, V, F>(distribution: D, f: F) -> V
+where
+    D: rand::distributions::Distribution<T>,
+    V: VectorSpace<Scalar = f64>,
+    F: Fn(T) -> V,
+{
+    let mut rng = rng();
+    let sum = (0..SAMPLES).fold(V::zero(), |acc, _| acc + f(rng.sample(&distribution)));
+    sum / SAMPLES as f64
+}
+
+#[test]
+fn test_unit_sphere() {
+    let mut rng = rng();
+    for _ in 0..100 {
+        let v: Vector3<f64> = rng.sample(UnitSphere);
+        assert_relative_eq!(v.magnitude(), 1.0, epsilon = 1e-12);
+        let p: Point3<f32> = rng.sample(UnitSphere);
+        assert_relative_eq!(p.to_vec().magnitude(), 1.0, epsilon = 1e-6);
+    }
+    assert_relative_eq!(
+        mean(UnitSphere, |v: Vector3<f64>| v),
+        Vector3::zero(),
+        epsilon = 0.02
+    );
+    assert_relative_eq!(
+        mean(UnitSphere, |v: Vector3<f64>| v.mul_element_wise(v)),
+        Vector3::from_value(1.0 / 3.0),
+        epsilon = 0.01
+    );
+}
+
+#[test]
+fn test_unit_ball() {
+    let mut rng = rng();
+    for _ in 0..100 {
+        let v: Vector3<f64> = rng.sample(UnitBall);
+        assert!(v.magnitude() <= 1.0);
+    }
+    assert_relative_eq!(
+        mean(UnitBall, |v: Vector3<f64>| v),
+        Vector3::zero(),
+        epsilon = 0.02
+    );
+    // the mean squared distance from the centre is 3/5
+    assert_relative_eq!(
+        mean(UnitBall, |v: Vector3<f64>| Vector1::new(v.magnitude2())).x,
+        0.6,
+        epsilon = 0.01
+    );
+    assert_relative_eq!(
+        UnitBall.pdf(Vector3::new(0.0, 0.5, 0.0)),
+        3.0 / (4.0 * std::f64::consts::PI)
+    );
+    assert_eq!(UnitBall.pdf(Vector3::new(0.0, 1.5, 0.0)), 0.0);
+}
+
+#[test]
+fn test_unit_disk() {
+    let mut rng = rng();
+    for _ in 0..100 {
+        let v: Vector2<f64> = rng.sample(UnitDisk);
+        assert!(v.magnitude() <= 1.0);
+        let _: Point2<f32> = rng.sample(UnitDisk);
+    }
+    assert_relative_eq!(
+        mean(UnitDisk, |v: Vector2<f64>| v),
+        Vector2::zero(),
+        epsilon = 0.02
+    );
+    // the mean squared distance from the centre is 1/2
+    assert_relative_eq!(
+        mean(UnitDisk, |v: Vector2<f64>| Vector1::new(v.magnitude2())).x,
+        0.5,
+        epsilon = 0.01
+    );
+    assert_relative_eq!(
+        UnitDisk.pdf(Vector2::new(0.5, 0.5)),
+        1.0 / std::f64::consts::PI
+    );
+    assert_eq!(UnitDisk.pdf(Vector2::new(1.0, 1.0)), 0.0);
+}
+
+#[test]
+fn test_unit_hemisphere() {
+    let mut rng = rng();
+    for _ in 0..100 {
+        let v: Vector3<f64> = rng.sample(UnitHemisphere);
+        assert_relative_eq!(v.magnitude(), 1.0, epsilon = 1e-12);
+        assert!(v.z >= 0.0);
+    }
+    assert_relative_eq!(
+        mean(UnitHemisphere, |v: Vector3<f64>| v),
+        Vector3::new(0.0, 0.0, 0.5),
+        epsilon = 0.02
+    );
+}
+
+#[test]
+fn test_cosine_hemisphere() {
+    let mut rng = rng();
+    for _ in 0..100 {
+        let v: Vector3<f64> = rng.sample(CosineHemisphere);
+        assert_relative_eq!(v.magnitude(), 1.0, epsilon = 1e-12);
+        assert!(v.z >= 0.0);
+    }
+    // the mean cosine is 2/3, against 1/2 for the uniform hemisphere
+    assert_relative_eq!(
+        mean(CosineHemisphere, |v: Vector3<f64>| v),
+        Vector3::new(0.0, 0.0, 2.0 / 3.0),
+        epsilon = 0.02
+    );
+    assert_relative_eq!(
+        CosineHemisphere.pdf(Vector3::<f64>::unit_z()),
+        1.0 / std::f64::consts::PI
+    );
+    assert_eq!(CosineHemisphere.pdf(-Vector3::<f64>::unit_z()), 0.0);
+}
+
+#[test]
+fn test_unit_cone() {
+    let cone = UnitCone::new(Deg(30.0f64));
+    let cos = 30f64.to_radians().cos();
+    let mut rng = rng();
+    for _ in 0..100 {
+        let v: Vector3<f64> = rng.sample(cone);
+        assert_relative_eq!(v.magnitude(), 1.0, epsilon = 1e-12);
+        assert!(v.z >= cos - 1e-12);
+        let _: Point3<f64> = rng.sample(cone);
+    }
+    assert_relative_eq!(
+        mean(cone, |v: Vector3<f64>| v),
+        Vector3::new(0.0, 0.0, (1.0 + cos) / 2.0),
+        epsilon = 0.01
+    );
+
+    // a cone with a half angle of a half turn covers the whole sphere
+    let sphere = UnitCone::new(Deg(180.0f64));
+    assert_relative_eq!(
+        sphere.pdf(-Vector3::unit_z()),
+        UnitSphere.pdf(Vector3::unit_z())
+    );
+}
+
+#[test]
+#[should_panic(expected = "The half angle of a cone must be between zero and a half turn")]
+fn test_unit_cone_out_of_range() {
+    UnitCone::new(Deg(270.0f64));
+}
+
+#[test]
+fn test_pdfs_integrate_to_one() {
+    // the mean of a density over directions that are uniform on the sphere,
+    // multiplied by the area of the sphere, is its integral
+    let area = 4.0 * std::f64::consts::PI;
+    let cone = UnitCone::new(Deg(60.0f64));
+    let integral = |pdf: &dyn Fn(Vector3<f64>) -> f64| {
+        mean(UnitSphere, |v: Vector3<f64>| Vector1::new(pdf(v))).x * area
+    };
+    assert_relative_eq!(integral(&|v| UnitSphere.pdf(v)), 1.0, epsilon = 1e-12);
+    assert_relative_eq!(integral(&|v| UnitHemisphere.pdf(v)), 1.0, epsilon = 0.02);
+    assert_relative_eq!(integral(&|v| CosineHemisphere.pdf(v)), 1.0, epsilon = 0.02);
+    assert_relative_eq!(integral(&|v| cone.pdf(v)), 1.0, epsilon = 0.03);
+}
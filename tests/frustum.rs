@@ -0,0 +1,219 @@
+// Copyright 2019 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn view_frustum() -> Frustum<f64> {
+    Frustum::from_matrix4(perspective(Deg(90.0), 1.0, 1.0, 10.0))
+}
+
+#[test]
+fn test_planes() {
+    let f = view_frustum();
+    let h = 0.5f64.sqrt();
+    assert_relative_eq!(
+        f.left,
+        Plane::new(Vector3::new(h, 0.0, -h), 0.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        f.right,
+        Plane::new(Vector3::new(-h, 0.0, -h), 0.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        f.bottom,
+        Plane::new(Vector3::new(0.0, h, -h), 0.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        f.top,
+        Plane::new(Vector3::new(0.0, -h, -h), 0.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        f.near,
+        Plane::new(-Vector3::unit_z(), -1.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(f.far, Plane::new(Vector3::unit_z(), 10.0), epsilon = 1e-12);
+}
+
+#[test]
+fn test_corners() {
+    let corners = view_frustum().corners();
+    let expected = [
+        Point3::new(-1.0, -1.0, -1.0),
+        Point3::new(1.0, -1.0, -1.0),
+        Point3::new(-1.0, 1.0, -1.0),
+        Point3::new(1.0, 1.0, -1.0),
+        Point3::new(-10.0, -10.0, -10.0),
+        Point3::new(10.0, -10.0, -10.0),
+        Point3::new(-10.0, 10.0, -10.0),
+        Point3::new(10.0, 10.0, -10.0),
+    ];
+    for (found, expected) in corners.iter().zip(expected.iter()) {
+        assert_relative_eq!(found, expected, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_corners_world_space() {
+    let proj = perspective(Deg(60.0), 1.5, 0.5, 50.0);
+    let view = Matrix4::look_at(
+        Point3::new(3.0, 2.0, 5.0),
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::unit_y(),
+    );
+    let view_proj = proj * view;
+    let frustum = Frustum::from(view_proj);
+
+    // every corner maps onto a corner of the clip volume
+    let inv = view_proj.invert().unwrap();
+    for (i, corner) in frustum.corners().iter().enumerate() {
+        let ndc = Point3::new(
+            if i & 1 == 0 { -1.0 } else { 1.0 },
+            if i & 2 == 0 { -1.0 } else { 1.0 },
+            if i & 4 == 0 { -1.0 } else { 1.0 },
+        );
+        assert_relative_eq!(*corner, inv.transform_point(ndc), epsilon = 1e-9);
+    }
+}
+
+#[test]
+fn test_corners_infinite_far() {
+    let proj: Matrix4<f64> = InfinitePerspectiveFov {
+        fovy: Deg(90.0).into(),
+        aspect: 1.0,
+        near: 1.0,
+        clip_space: ClipSpace::OPENGL,
+    }
+    .into();
+    let corners = Frustum::from(proj).corners();
+    assert_relative_eq!(corners[0], Point3::new(-1.0, -1.0, -1.0), epsilon = 1e-12);
+    assert_relative_eq!(corners[3], Point3::new(1.0, 1.0, -1.0), epsilon = 1e-12);
+    for corner in &corners[4..] {
+        assert!(!corner.x.is_finite() || !corner.y.is_finite() || !corner.z.is_finite());
+    }
+}
+
+#[test]
+fn test_relate_point() {
+    let f = view_frustum();
+    assert_eq!(
+        f.relate_point(Point3::new(0.0, 0.0, -5.0)),
+        Relation::Inside
+    );
+    assert_eq!(
+        f.relate_point(Point3::new(0.0, 0.0, -0.5)),
+        Relation::Outside
+    );
+    assert_eq!(
+        f.relate_point(Point3::new(0.0, 0.0, -11.0)),
+        Relation::Outside
+    );
+    assert_eq!(
+        f.relate_point(Point3::new(6.0, 0.0, -5.0)),
+        Relation::Outside
+    );
+    assert_eq!(
+        f.relate_point(Point3::new(0.0, 0.0, -1.0)),
+        Relation::Intersecting
+    );
+    assert!(f.contains_point(Point3::new(0.0, 0.0, -1.0)));
+    assert!(!f.contains_point(Point3::new(0.0, -6.0, -5.0)));
+}
+
+#[test]
+fn test_relate_sphere() {
+    let f = view_frustum();
+    assert_eq!(
+        f.relate_sphere(Point3::new(0.0, 0.0, -5.0), 1.0),
+        Relation::Inside
+    );
+    assert_eq!(
+        f.relate_sphere(Point3::new(0.0, 0.0, -10.0), 1.0),
+        Relation::Intersecting
+    );
+    assert_eq!(
+        f.relate_sphere(Point3::new(0.0, 0.0, -12.0), 1.0),
+        Relation::Outside
+    );
+    assert_eq!(
+        f.relate_sphere(Point3::new(0.0, 0.0, 5.0), 1.0),
+        Relation::Outside
+    );
+}
+
+#[test]
+fn test_relate_aabb() {
+    let f = view_frustum();
+    let aabb = |min: [f64; 3], max: [f64; 3]| Aabb3::new(min.into(), max.into());
+    assert_eq!(
+        f.relate_aabb(&aabb([-1.0, -1.0, -6.0], [1.0, 1.0, -4.0])),
+        Relation::Inside
+    );
+    assert_eq!(
+        f.relate_aabb(&aabb([-1.0, -1.0, -12.0], [1.0, 1.0, -8.0])),
+        Relation::Intersecting
+    );
+    assert_eq!(
+        f.relate_aabb(&aabb([6.0, -1.0, -6.0], [8.0, 1.0, -4.0])),
+        Relation::Outside
+    );
+    assert_eq!(
+        f.relate_aabb(&aabb([-1.0, -1.0, 1.0], [1.0, 1.0, 2.0])),
+        Relation::Outside
+    );
+}
+
+#[test]
+fn test_from_matrix4_clip_space() {
+    let expected = view_frustum();
+    for &depth in &[DepthRange::NegativeOneToOne, DepthRange::ZeroToOne] {
+        for &reverse_z in &[false, true] {
+            let clip_space = ClipSpace::new(depth, Handedness::RightHanded, reverse_z);
+            let m: Matrix4<f64> = PerspectiveFov {
+                fovy: Deg(90.0).into(),
+                aspect: 1.0,
+                near: 1.0,
+                far: 10.0,
+                clip_space: clip_space,
+            }
+            .into();
+            let f = Frustum::from_matrix4_clip_space(m, clip_space);
+            for (plane, expected) in f.planes().iter().zip(expected.planes().iter()) {
+                assert_relative_eq!(plane, expected, epsilon = 1e-12);
+            }
+        }
+    }
+
+    // a left-handed projection gives the mirror image of the frustum
+    let clip_space = ClipSpace::new(DepthRange::ZeroToOne, Handedness::LeftHanded, true);
+    let m: Matrix4<f64> = PerspectiveFov {
+        fovy: Deg(90.0).into(),
+        aspect: 1.0,
+        near: 1.0,
+        far: 10.0,
+        clip_space: clip_space,
+    }
+    .into();
+    let f = Frustum::from_matrix4_clip_space(m, clip_space);
+    assert_relative_eq!(f.near, Plane::new(Vector3::unit_z(), -1.0), epsilon = 1e-12);
+    assert_relative_eq!(f.far, Plane::new(-Vector3::unit_z(), 10.0), epsilon = 1e-12);
+}
@@ -204,6 +204,62 @@ pub mod matrix2 {
         let rot2 = Matrix2::look_at(-V, Vector2::unit_y());
         assert_eq!(rot2 * Vector2::unit_x(), (-V).normalize());
     }
+
+    #[test]
+    fn test_symmetric_eigen() {
+        let (values, rot) = C.symmetric_eigen();
+        assert_ulps_eq!(values, Vector2::new(3.0, 1.0));
+        let h = 0.5f64.sqrt();
+        assert_ulps_eq!(rot.rotate_vector(Vector2::unit_x()), Vector2::new(h, h));
+
+        let m: Matrix2<f64> = rot.into();
+        assert_relative_eq!(
+            m * Matrix2::from_diagonal(values) * m.transpose(),
+            C,
+            epsilon = 1e-12
+        );
+
+        // already diagonal, in increasing order
+        let (values, rot) = Matrix2::new(1.0f64, 0.0, 0.0, 4.0).symmetric_eigen();
+        assert_ulps_eq!(values, Vector2::new(4.0, 1.0));
+        assert_ulps_eq!(rot.rotate_vector(Vector2::unit_x()), Vector2::unit_y());
+    }
+
+    #[test]
+    fn test_svd() {
+        let (u, sigma, vt) = A.svd();
+        let root = 221.0f64.sqrt();
+        assert_relative_eq!(
+            sigma,
+            Vector2::new((15.0 + root).sqrt(), (15.0 - root).sqrt()),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(u * Matrix2::from_diagonal(sigma) * vt, A, epsilon = 1e-12);
+        assert_relative_eq!(u.transpose() * u, Matrix2::identity(), epsilon = 1e-12);
+        assert_relative_eq!(vt * vt.transpose(), Matrix2::identity(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_signed_svd() {
+        // A has a negative determinant, so it contains a reflection
+        let (u, sigma, vt) = A.signed_svd();
+        let (_, unsigned, _) = A.svd();
+        assert_relative_eq!(
+            sigma,
+            Vector2::new(unsigned.x, -unsigned.y),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(u.determinant(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(vt.determinant(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(u * Matrix2::from_diagonal(sigma) * vt, A, epsilon = 1e-12);
+
+        let (u, sigma, vt) = B.signed_svd();
+        assert!(sigma.y < 0.0);
+        assert_relative_eq!(u * Matrix2::from_diagonal(sigma) * vt, B, epsilon = 1e-12);
+        let (u, sigma, vt) = C.signed_svd();
+        assert_relative_eq!(sigma, Vector2::new(3.0, 1.0), epsilon = 1e-12);
+        assert_relative_eq!(u, vt.transpose(), epsilon = 1e-12);
+    }
 }
 
 pub mod matrix3 {
@@ -726,6 +782,145 @@ pub mod matrix3 {
             );
         }
     }
+
+    #[test]
+    fn test_symmetric_eigen() {
+        let (values, rot) = D.symmetric_eigen();
+        // the characteristic polynomial of D factors as
+        // (2 - λ) * (λ^2 - 7λ + 4)
+        let root = 33.0f64.sqrt();
+        assert_relative_eq!(
+            values,
+            Vector3::new((7.0 + root) / 2.0, 2.0, (7.0 - root) / 2.0),
+            epsilon = 1e-12
+        );
+        let m: Matrix3<f64> = rot.into();
+        assert_relative_eq!(m.determinant(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(m.transpose() * m, Matrix3::identity(), epsilon = 1e-12);
+        assert_relative_eq!(
+            m * Matrix3::from_diagonal(values) * m.transpose(),
+            D,
+            epsilon = 1e-12
+        );
+        for i in 0..3 {
+            assert_relative_eq!(D * m[i], m[i] * values[i], epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_symmetric_eigen_rotated() {
+        let rot = Matrix3::from_axis_angle(Vector3::new(1.0f64, 2.0, 3.0).normalize(), Deg(70.0));
+        let diag = Vector3::new(-1.0, 5.0, 2.0);
+        let m = rot * Matrix3::from_diagonal(diag) * rot.transpose();
+
+        let (values, basis) = m.symmetric_eigen();
+        assert_relative_eq!(values, Vector3::new(5.0, 2.0, -1.0), epsilon = 1e-12);
+        let axes: Matrix3<f64> = basis.into();
+        // eigenvectors are only defined up to their sign
+        assert_relative_eq!(axes.x.dot(rot.y).abs(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(axes.y.dot(rot.z).abs(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(axes.z.dot(rot.x).abs(), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_symmetric_eigen_repeated() {
+        let (values, basis) = Matrix3::from_value(2.0f64).symmetric_eigen();
+        assert_ulps_eq!(values, Vector3::new(2.0, 2.0, 2.0));
+        assert_ulps_eq!(basis, Basis3::one());
+
+        let (values, _) = Matrix3::<f64>::zero().symmetric_eigen();
+        assert_ulps_eq!(values, Vector3::zero());
+    }
+
+    fn check_svd(m: Matrix3<f64>, (u, sigma, vt): (Matrix3<f64>, Vector3<f64>, Matrix3<f64>)) {
+        assert_relative_eq!(u * Matrix3::from_diagonal(sigma) * vt, m, epsilon = 1e-12);
+        assert_relative_eq!(u.transpose() * u, Matrix3::identity(), epsilon = 1e-12);
+        assert_relative_eq!(vt * vt.transpose(), Matrix3::identity(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_svd() {
+        for &m in [A, B, C, D, Matrix3::zero(), Matrix3::identity()].iter() {
+            let (u, sigma, vt) = m.svd();
+            check_svd(m, (u, sigma, vt));
+            assert!(sigma.x >= sigma.y && sigma.y >= sigma.z && sigma.z >= 0.0);
+        }
+
+        // A has rank 2, so its last singular value is zero
+        let (_, sigma, _) = A.svd();
+        assert_relative_eq!(sigma.z, 0.0, epsilon = 1e-12);
+
+        // D is symmetric positive definite, so its singular values are its
+        // eigenvalues
+        let (values, _) = D.symmetric_eigen();
+        assert_relative_eq!(D.svd().1, values, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_signed_svd() {
+        let rot_u = Matrix3::from_axis_angle(Vector3::new(1.0f64, 2.0, 3.0).normalize(), Deg(70.0));
+        let rot_v = Matrix3::from_angle_x(Deg(25.0)) * Matrix3::from_angle_z(Deg(-40.0));
+        let m = rot_u * Matrix3::from_diagonal(Vector3::new(-2.0, 3.0, 1.0)) * rot_v;
+
+        let (_, sigma, _) = m.svd();
+        assert_relative_eq!(sigma, Vector3::new(3.0, 2.0, 1.0), epsilon = 1e-12);
+
+        let (u, sigma, vt) = m.signed_svd();
+        check_svd(m, (u, sigma, vt));
+        assert_relative_eq!(sigma, Vector3::new(3.0, 2.0, -1.0), epsilon = 1e-12);
+        assert_relative_eq!(u.determinant(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(vt.determinant(), 1.0, epsilon = 1e-12);
+
+        for &m in [A, B, C, D].iter() {
+            let (u, sigma, vt) = m.signed_svd();
+            check_svd(m, (u, sigma, vt));
+            assert_relative_eq!(u.determinant(), 1.0, epsilon = 1e-12);
+            assert_relative_eq!(vt.determinant(), 1.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_polar_decompose() {
+        let rot = Matrix3::from_axis_angle(Vector3::new(1.0f64, 2.0, 3.0).normalize(), Deg(70.0));
+        let stretch = Matrix3::new(2.0, 0.5, 0.0, 0.5, 1.0, 0.25, 0.0, 0.25, 3.0);
+        let (r, p) = (rot * stretch).polar_decompose();
+        assert_relative_eq!(r, rot, epsilon = 1e-12);
+        assert_relative_eq!(p, stretch, epsilon = 1e-12);
+
+        for &m in [A, B, C, D].iter() {
+            let (r, p) = m.polar_decompose();
+            assert_relative_eq!(r * p, m, epsilon = 1e-12);
+            assert_relative_eq!(r.transpose() * r, Matrix3::identity(), epsilon = 1e-12);
+            assert_relative_eq!(r.determinant(), 1.0, epsilon = 1e-12);
+            assert_relative_eq!(p, p.transpose(), epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_orthonormalize() {
+        let rot = Matrix3::from_angle_x(Deg(25.0)) * Matrix3::from_angle_z(Deg(-40.0));
+        for &method in [
+            Orthonormalization::GramSchmidt,
+            Orthonormalization::NearestRotation,
+        ]
+        .iter()
+        {
+            assert_relative_eq!(rot.orthonormalize(method), rot, epsilon = 1e-12);
+
+            let drifted = rot + Matrix3::new(1e-4, 0.0, -2e-4, 0.0, 3e-4, 0.0, 1e-4, 0.0, 0.0);
+            let m = drifted.orthonormalize(method);
+            assert_relative_eq!(m.transpose() * m, Matrix3::identity(), epsilon = 1e-12);
+            assert_relative_eq!(m.determinant(), 1.0, epsilon = 1e-12);
+            assert_relative_eq!(m, rot, epsilon = 1e-3);
+        }
+
+        // Gram-Schmidt keeps the direction of the first column
+        let m = Matrix3::new(2.0f64, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 5.0);
+        assert_ulps_eq!(
+            m.orthonormalize(Orthonormalization::GramSchmidt),
+            Matrix3::identity()
+        );
+    }
 }
 
 pub mod matrix4 {
@@ -1127,6 +1322,27 @@ pub mod matrix4 {
         );
     }
 
+    #[test]
+    fn test_svd() {
+        for &m in [A, B, C, D].iter() {
+            let (u, sigma, vt) = m.svd();
+            assert_relative_eq!(u * Matrix4::from_diagonal(sigma) * vt, m, epsilon = 1e-12);
+            assert_relative_eq!(u.transpose() * u, Matrix4::identity(), epsilon = 1e-12);
+            assert_relative_eq!(vt * vt.transpose(), Matrix4::identity(), epsilon = 1e-12);
+            assert!(sigma.x >= sigma.y && sigma.y >= sigma.z && sigma.z >= sigma.w);
+            assert!(sigma.w >= 0.0);
+
+            let (u, sigma, vt) = m.signed_svd();
+            assert_relative_eq!(u * Matrix4::from_diagonal(sigma) * vt, m, epsilon = 1e-12);
+            assert_relative_eq!(u.determinant(), 1.0, epsilon = 1e-12);
+            assert_relative_eq!(vt.determinant(), 1.0, epsilon = 1e-12);
+        }
+
+        // A and B have rank 2
+        assert_relative_eq!(A.svd().1.z, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(B.svd().1.w, 0.0, epsilon = 1e-12);
+    }
+
     mod from {
         use cgmath::*;
 
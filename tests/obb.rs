@@ -0,0 +1,129 @@
+// Copyright 2019 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn rotated_box() -> Obb3<f64, Quaternion<f64>> {
+    Obb3::new(
+        Point3::new(1.0, 2.0, 3.0),
+        Vector3::new(2.0, 1.0, 0.5),
+        Quaternion::from_angle_z(Deg(45.0)),
+    )
+}
+
+#[test]
+fn test_contains_point() {
+    let obb = rotated_box();
+    let h = 0.5f64.sqrt();
+    assert!(obb.contains_point(Point3::new(1.0, 2.0, 3.0)));
+    // 1.9 along the rotated x axis
+    assert!(obb.contains_point(Point3::new(1.0 + 1.9 * h, 2.0 + 1.9 * h, 3.0)));
+    // but only 1 along the rotated y axis
+    assert!(!obb.contains_point(Point3::new(1.0 - 1.1 * h, 2.0 + 1.1 * h, 3.0)));
+    assert!(!obb.contains_point(Point3::new(1.0, 2.0, 3.6)));
+}
+
+#[test]
+fn test_closest_point() {
+    let obb = rotated_box();
+    let h = 0.5f64.sqrt();
+    let inside = Point3::new(1.5, 2.0, 3.2);
+    assert_relative_eq!(obb.closest_point(inside), inside, epsilon = 1e-12);
+
+    let outside = Point3::new(1.0 + 5.0 * h, 2.0 + 5.0 * h, 4.0);
+    let expected = Point3::new(1.0 + 2.0 * h, 2.0 + 2.0 * h, 3.5);
+    assert_relative_eq!(obb.closest_point(outside), expected, epsilon = 1e-12);
+}
+
+#[test]
+fn test_corners() {
+    let obb = Obb3::new(
+        Point3::new(0.0f64, 0.0, 0.0),
+        Vector3::new(1.0, 2.0, 3.0),
+        Basis3::one(),
+    );
+    let aabb = Aabb3::new(Point3::new(-1.0, -2.0, -3.0), Point3::new(1.0, 2.0, 3.0));
+    assert_eq!(obb.corners(), aabb.corners());
+}
+
+#[test]
+fn test_intersects() {
+    let a = Obb3::new(
+        Point3::new(0.0f64, 0.0, 0.0),
+        Vector3::new(1.0, 1.0, 1.0),
+        Quaternion::one(),
+    );
+
+    // a diamond whose corner pokes into the box
+    let b = Obb3::new(
+        Point3::new(2.3, 0.0, 0.0),
+        Vector3::new(1.0, 1.0, 1.0),
+        Basis3::from_angle_z(Deg(45.0)),
+    );
+    assert!(a.intersects(&b));
+    assert!(b.intersects(&a));
+
+    // the same diamond, but moved just out of reach
+    let c = Obb3::new(Point3::new(2.5, 0.0, 0.0), b.half_extents, b.rot);
+    assert!(!a.intersects(&c));
+    assert!(!c.intersects(&a));
+
+    // only separated along the cross product of two edges, so the face
+    // normals alone would report an overlap
+    let rot = Quaternion::from_angle_x(Deg(15.0)) * Quaternion::from_angle_y(Deg(15.0));
+    let d = Obb3::new(Point3::new(1.8, 2.2, 0.0), a.half_extents, rot);
+    assert!(!a.intersects(&d));
+    assert!(!d.intersects(&a));
+    let e = Obb3::new(Point3::new(1.7, 2.0, 0.0), a.half_extents, rot);
+    assert!(a.intersects(&e));
+    assert!(e.intersects(&a));
+
+    let far = Obb3::new(Point3::new(0.0, 10.0, 0.0), a.half_extents, a.rot);
+    assert!(!a.intersects(&far));
+    assert!(a.intersects(&a));
+}
+
+#[test]
+fn test_from_points() {
+    let expected = rotated_box();
+    let mut points = expected.corners().to_vec();
+    // the centers of the faces keep the points symmetric about the axes
+    for &v in [
+        Vector3::unit_x() * 2.0,
+        Vector3::unit_y(),
+        Vector3::unit_z() * 0.5,
+    ]
+    .iter()
+    {
+        points.push(expected.center + expected.rot.rotate_vector(v));
+        points.push(expected.center - expected.rot.rotate_vector(v));
+    }
+    let obb: Obb3<f64, Basis3<f64>> = Obb3::from_points(&points).unwrap();
+    for &p in points.iter() {
+        assert!(obb.contains_point(p + (obb.center - p) * 1e-9));
+    }
+    assert_relative_eq!(obb.center, expected.center, epsilon = 1e-9);
+
+    let mut found = [obb.half_extents.x, obb.half_extents.y, obb.half_extents.z];
+    found.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_relative_eq!(found[0], 0.5, epsilon = 1e-9);
+    assert_relative_eq!(found[1], 1.0, epsilon = 1e-9);
+    assert_relative_eq!(found[2], 2.0, epsilon = 1e-9);
+
+    assert!(Obb3::<f64, Quaternion<f64>>::from_points(&[]).is_none());
+}
@@ -0,0 +1,159 @@
+// Copyright 2019 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_from_points() {
+    let plane = Plane::from_points(
+        Point3::new(0.0f64, 2.0, 0.0),
+        Point3::new(0.0, 2.0, 1.0),
+        Point3::new(1.0, 2.0, 0.0),
+    )
+    .unwrap();
+    assert_ulps_eq!(plane, Plane::new(Vector3::unit_y(), -2.0));
+
+    let collinear = Plane::from_points(
+        Point3::new(0.0f64, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(2.0, 2.0, 2.0),
+    );
+    assert!(collinear.is_none());
+
+    // degeneracy does not depend on the size of the triangle
+    let small = Plane::from_points(
+        Point3::new(0.0f32, 0.0, 0.0),
+        Point3::new(0.0, 0.0, 1e-3),
+        Point3::new(1e-3, 0.0, 0.0),
+    );
+    assert_ulps_eq!(small.unwrap(), Plane::new(Vector3::unit_y(), 0.0));
+    let large = Plane::from_points(
+        Point3::new(0.0f32, 0.0, 0.0),
+        Point3::new(1e4, 1e4, 1e4),
+        Point3::new(2e4, 2e4, 2e4 + 1e-3),
+    );
+    assert!(large.is_none());
+}
+
+#[test]
+fn test_from_point_normal() {
+    let plane =
+        Plane::from_point_normal(Point3::new(1.0f64, 5.0, 3.0), Vector3::new(0.0, 0.0, -4.0));
+    assert_ulps_eq!(plane, Plane::new(-Vector3::unit_z(), 3.0));
+}
+
+#[test]
+fn test_signed_distance() {
+    let plane = Plane::from_point_normal(Point3::new(0.0f64, 0.0, 1.0), Vector3::unit_z());
+    assert_ulps_eq!(plane.signed_distance(Point3::new(4.0, -2.0, 3.0)), 2.0);
+    assert_ulps_eq!(plane.signed_distance(Point3::new(4.0, -2.0, -1.0)), -2.0);
+    assert_ulps_eq!(
+        plane.flip().signed_distance(Point3::new(4.0, -2.0, -1.0)),
+        2.0
+    );
+}
+
+#[test]
+fn test_project_point() {
+    let plane = Plane::from_abcd(1.0f64, 1.0, 0.0, -2.0);
+    let p = plane.project_point(Point3::new(3.0, 3.0, 7.0));
+    assert_relative_eq!(p, Point3::new(1.0, 1.0, 7.0), epsilon = 1e-12);
+    assert_relative_eq!(plane.signed_distance(p), 0.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_vector4_round_trip() {
+    let plane = Plane::from_abcd(0.0f64, 3.0, 4.0, 10.0);
+    assert_ulps_eq!(plane, Plane::new(Vector3::new(0.0, 0.6, 0.8), 2.0));
+
+    let v: Vector4<f64> = plane.into();
+    assert_ulps_eq!(v, Vector4::new(0.0, 0.6, 0.8, 2.0));
+    assert_ulps_eq!(Plane::from(v), plane);
+}
+
+#[test]
+fn test_transform() {
+    let plane =
+        Plane::from_point_normal(Point3::new(1.0f64, 2.0, 3.0), Vector3::new(1.0, 1.0, 0.0));
+    let m = Matrix4::from_translation(Vector3::new(-2.0, 0.5, 4.0))
+        * Matrix4::from_nonuniform_scale(2.0, 0.5, 3.0)
+        * Matrix4::from_angle_z(Deg(25.0));
+    let transformed = plane.transform(&m).unwrap();
+
+    // points on the plane stay on the transformed plane
+    let on_plane = [
+        Point3::new(1.0, 2.0, 3.0),
+        Point3::new(0.0, 3.0, 3.0),
+        Point3::new(1.0, 2.0, -5.0),
+    ];
+    for &p in on_plane.iter() {
+        assert_relative_eq!(
+            transformed.signed_distance(m.transform_point(p)),
+            0.0,
+            epsilon = 1e-12
+        );
+    }
+    assert_relative_eq!(transformed.normal.magnitude(), 1.0, epsilon = 1e-12);
+
+    // the side of the plane a point is on is preserved
+    let above = Point3::new(2.0, 3.0, 0.0);
+    assert!(plane.signed_distance(above) > 0.0);
+    assert!(transformed.signed_distance(m.transform_point(above)) > 0.0);
+
+    assert!(plane.transform(&Matrix4::from_scale(0.0)).is_none());
+}
+
+#[test]
+fn test_intersect_ray() {
+    let plane = Plane::new(Vector3::unit_y(), 1.0);
+    let ray = Ray3::new(Point3::new(0.0f64, 3.0, 0.0), -Vector3::unit_y());
+    let (t, p) = plane.intersect_ray(&ray).unwrap();
+    assert_ulps_eq!(t, 4.0);
+    assert_ulps_eq!(p, Point3::new(0.0, -1.0, 0.0));
+}
+
+#[test]
+fn test_from_reflection_plane() {
+    let plane = Plane::from_point_normal(Point3::new(0.0f64, 1.0, 0.0), Vector3::unit_y());
+    let m = Matrix4::from_reflection_plane(plane);
+    assert_ulps_eq!(
+        m.transform_point(Point3::new(3.0, 4.0, 5.0)),
+        Point3::new(3.0, -2.0, 5.0)
+    );
+    assert_ulps_eq!(
+        m.transform_vector(Vector3::new(1.0, 1.0, 1.0)),
+        Vector3::new(1.0, -1.0, 1.0)
+    );
+    assert_ulps_eq!(m * m, Matrix4::identity());
+
+    let plane = Plane::from_abcd(1.0f64, -2.0, 2.0, 3.0);
+    let m = Matrix4::from_reflection_plane(plane);
+    let p = Point3::new(0.5, 7.0, -1.0);
+    let q = m.transform_point(p);
+    assert_relative_eq!(
+        plane.signed_distance(q),
+        -plane.signed_distance(p),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        plane.project_point(q),
+        plane.project_point(p),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(m.determinant(), -1.0, epsilon = 1e-12);
+}
@@ -13,9 +13,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+extern crate approx;
 extern crate cgmath;
 
-use cgmath::{ortho, Matrix4, Vector4};
+use cgmath::*;
 
 #[test]
 fn test_ortho_scale() {
@@ -66,3 +67,839 @@ fn test_ortho_translate() {
     let orig = o * vec_orig;
     assert_eq!(orig, Vector4::new(1., 1., 1., 1.));
 }
+
+fn clip_spaces() -> Vec<ClipSpace> {
+    let mut spaces = Vec::new();
+    for &depth in &[DepthRange::NegativeOneToOne, DepthRange::ZeroToOne] {
+        for &handedness in &[Handedness::RightHanded, Handedness::LeftHanded] {
+            for &reverse_z in &[false, true] {
+                spaces.push(ClipSpace::new(depth, handedness, reverse_z));
+            }
+        }
+    }
+    spaces
+}
+
+/// Project a point at `distance` in front of the camera to normalized device
+/// coordinates.
+fn to_ndc(m: Matrix4<f64>, x: f64, y: f64, distance: f64, clip_space: ClipSpace) -> Point3<f64> {
+    let forward: f64 = clip_space.forward();
+    Point3::from_homogeneous(m * Vector4::new(x, y, distance * forward, 1.0))
+}
+
+#[test]
+fn test_clip_space_depths() {
+    let gl = ClipSpace::default();
+    assert_eq!(gl, ClipSpace::OPENGL);
+    assert_eq!(gl.near_depth::<f32>(), -1.0);
+    assert_eq!(gl.far_depth::<f32>(), 1.0);
+    assert_eq!(gl.forward::<f32>(), -1.0);
+
+    let vulkan = ClipSpace::new(DepthRange::ZeroToOne, Handedness::LeftHanded, true);
+    assert_eq!(vulkan.near_depth::<f32>(), 1.0);
+    assert_eq!(vulkan.far_depth::<f32>(), 0.0);
+    assert_eq!(vulkan.forward::<f32>(), 1.0);
+}
+
+#[test]
+fn test_perspective_fov_clip_space() {
+    for &clip_space in &clip_spaces() {
+        let m: Matrix4<f64> = PerspectiveFov {
+            fovy: Deg(90.0).into(),
+            aspect: 2.0,
+            near: 0.5,
+            far: 50.0,
+            clip_space: clip_space,
+        }
+        .into();
+
+        let near = to_ndc(m, 1.0, 0.5, 0.5, clip_space);
+        let far = to_ndc(m, -100.0, -50.0, 50.0, clip_space);
+        assert_relative_eq!(
+            near,
+            Point3::new(1.0, 1.0, clip_space.near_depth()),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            far,
+            Point3::new(-1.0, -1.0, clip_space.far_depth()),
+            epsilon = 1e-12
+        );
+
+        // depth varies monotonically between the two planes
+        let middle = to_ndc(m, 0.0, 0.0, 5.0, clip_space);
+        let (lo, hi): (f64, f64) = if clip_space.reverse_z {
+            (clip_space.far_depth(), clip_space.near_depth())
+        } else {
+            (clip_space.near_depth(), clip_space.far_depth())
+        };
+        assert!(lo < middle.z && middle.z < hi);
+    }
+}
+
+#[test]
+fn test_perspective_clip_space() {
+    for &clip_space in &clip_spaces() {
+        let m: Matrix4<f64> = Perspective {
+            left: -1.0,
+            right: 3.0,
+            bottom: -2.0,
+            top: 1.0,
+            near: 1.0,
+            far: 20.0,
+            clip_space: clip_space,
+        }
+        .into();
+
+        let near = to_ndc(m, -1.0, 1.0, 1.0, clip_space);
+        let far = to_ndc(m, 60.0, -40.0, 20.0, clip_space);
+        assert_relative_eq!(
+            near,
+            Point3::new(-1.0, 1.0, clip_space.near_depth()),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            far,
+            Point3::new(1.0, -1.0, clip_space.far_depth()),
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_ortho_clip_space() {
+    for &clip_space in &clip_spaces() {
+        let m: Matrix4<f64> = Ortho {
+            left: -4.0,
+            right: 4.0,
+            bottom: 0.0,
+            top: 3.0,
+            near: -1.0,
+            far: 9.0,
+            clip_space: clip_space,
+        }
+        .into();
+
+        let near = to_ndc(m, -4.0, 3.0, -1.0, clip_space);
+        let far = to_ndc(m, 4.0, 0.0, 9.0, clip_space);
+        assert_relative_eq!(
+            near,
+            Point3::new(-1.0, 1.0, clip_space.near_depth()),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            far,
+            Point3::new(1.0, -1.0, clip_space.far_depth()),
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_opengl_clip_space() {
+    // the default clip space gives the same matrix as `gluPerspective`
+    let (near, far) = (0.1f64, 100.0);
+    let f = 1.0 / (30.0f64.to_radians()).tan();
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    let expected = Matrix4::new(
+        f / 1.5, 0.0, 0.0, 0.0,
+        0.0, f, 0.0, 0.0,
+        0.0, 0.0, (far + near) / (near - far), -1.0,
+        0.0, 0.0, 2.0 * far * near / (near - far), 0.0,
+    );
+    assert_relative_eq!(
+        perspective(Deg(60.0), 1.5, near, far),
+        expected,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_projection_functions_with_clip_space() {
+    for &clip_space in &clip_spaces() {
+        assert_eq!(
+            perspective_with(Deg(60.0), 1.5, 0.1, 100.0, clip_space),
+            Matrix4::from(PerspectiveFov {
+                fovy: Deg(60.0).into(),
+                aspect: 1.5,
+                near: 0.1,
+                far: 100.0,
+                clip_space: clip_space,
+            })
+        );
+        assert_eq!(
+            frustum_with(-1.0, 2.0, -0.5, 0.5, 0.1, 100.0, clip_space),
+            Matrix4::from(Perspective {
+                left: -1.0,
+                right: 2.0,
+                bottom: -0.5,
+                top: 0.5,
+                near: 0.1,
+                far: 100.0,
+                clip_space: clip_space,
+            })
+        );
+        assert_eq!(
+            ortho_with(-1.0, 2.0, -0.5, 0.5, 0.1, 100.0, clip_space),
+            Matrix4::from(Ortho {
+                left: -1.0,
+                right: 2.0,
+                bottom: -0.5,
+                top: 0.5,
+                near: 0.1,
+                far: 100.0,
+                clip_space: clip_space,
+            })
+        );
+    }
+}
+
+fn infinite(clip_space: ClipSpace) -> InfinitePerspectiveFov<f64> {
+    InfinitePerspectiveFov {
+        fovy: Deg(60.0).into(),
+        aspect: 1.5,
+        near: 0.1,
+        clip_space: clip_space,
+    }
+}
+
+#[test]
+fn test_infinite_perspective() {
+    for &clip_space in &clip_spaces() {
+        let m: Matrix4<f64> = infinite(clip_space).into();
+
+        // the matrix is the limit of a finite projection with a distant far plane
+        let finite: Matrix4<f64> = PerspectiveFov {
+            fovy: Deg(60.0).into(),
+            aspect: 1.5,
+            near: 0.1,
+            far: 1e12,
+            clip_space: clip_space,
+        }
+        .into();
+        assert_relative_eq!(m, finite, epsilon = 1e-9);
+
+        let near = to_ndc(m, 0.0, 0.0, 0.1, clip_space);
+        assert_relative_eq!(near.z, clip_space.near_depth(), epsilon = 1e-12);
+        let far = to_ndc(m, 0.0, 0.0, 1e15, clip_space);
+        assert_relative_eq!(far.z, clip_space.far_depth(), epsilon = 1e-12);
+
+        // points at infinity in front of the camera land on the far plane
+        let forward: f64 = clip_space.forward();
+        let horizon = m * Vector4::new(0.0, 0.0, forward, 0.0);
+        assert_relative_eq!(horizon.z / horizon.w, clip_space.far_depth());
+    }
+}
+
+#[test]
+fn test_infinite_perspective_inverse() {
+    for &clip_space in &clip_spaces() {
+        let persp = infinite(clip_space);
+        let m: Matrix4<f64> = persp.into();
+        let inv = persp.inverse_matrix();
+        assert_relative_eq!(inv * m, Matrix4::identity(), epsilon = 1e-12);
+        assert_relative_eq!(m * inv, Matrix4::identity(), epsilon = 1e-12);
+
+        // the centre of the near plane unprojects to the point on the near
+        // plane in front of the camera
+        let ndc = Vector4::new(0.0, 0.0, clip_space.near_depth(), 1.0);
+        let forward: f64 = clip_space.forward();
+        assert_relative_eq!(
+            Point3::from_homogeneous(inv * ndc),
+            Point3::new(0.0, 0.0, 0.1 * forward),
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_inverse_matrix() {
+    for &clip_space in &clip_spaces() {
+        let fov = PerspectiveFov {
+            fovy: Deg(75.0).into(),
+            aspect: 16.0 / 9.0,
+            near: 0.25,
+            far: 300.0,
+            clip_space: clip_space,
+        };
+        let persp = Perspective {
+            left: -0.5,
+            right: 2.0,
+            bottom: -1.0,
+            top: 0.25,
+            near: 0.5,
+            far: 40.0,
+            clip_space: clip_space,
+        };
+        let ortho = Ortho {
+            left: -3.0,
+            right: 5.0,
+            bottom: 1.0,
+            top: 2.0,
+            near: -2.0,
+            far: 7.0,
+            clip_space: clip_space,
+        };
+
+        let pairs = [
+            (Matrix4::from(fov), fov.inverse_matrix()),
+            (Matrix4::from(persp), persp.inverse_matrix()),
+            (Matrix4::from(ortho), ortho.inverse_matrix()),
+        ];
+        for &(m, inv) in &pairs {
+            assert_relative_eq!(inv * m, Matrix4::identity(), epsilon = 1e-12);
+            assert_relative_eq!(inv, m.invert().unwrap(), epsilon = 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_linear_depth() {
+    for &clip_space in &clip_spaces() {
+        let fov = PerspectiveFov {
+            fovy: Deg(75.0).into(),
+            aspect: 16.0 / 9.0,
+            near: 0.25,
+            far: 300.0,
+            clip_space: clip_space,
+        };
+        let persp = fov.to_perspective();
+        let ortho = Ortho {
+            left: -3.0,
+            right: 5.0,
+            bottom: 1.0,
+            top: 2.0,
+            near: -2.0,
+            far: 7.0,
+            clip_space: clip_space,
+        };
+        let infinite = infinite(clip_space);
+
+        for &distance in &[0.25, 1.0, 12.5, 300.0] {
+            let ndc = to_ndc(fov.into(), 0.0, 0.0, distance, clip_space).z;
+            assert_relative_eq!(fov.linear_depth(ndc), distance, max_relative = 1e-9);
+            let ndc = to_ndc(persp.into(), 0.0, 0.0, distance, clip_space).z;
+            assert_relative_eq!(persp.linear_depth(ndc), distance, max_relative = 1e-9);
+            let ndc = to_ndc(infinite.into(), 0.0, 0.0, distance, clip_space).z;
+            assert_relative_eq!(infinite.linear_depth(ndc), distance, max_relative = 1e-9);
+        }
+        for &distance in &[-2.0, 0.0, 3.5, 7.0] {
+            let ndc = to_ndc(ortho.into(), 0.0, 0.0, distance, clip_space).z;
+            assert_relative_eq!(ortho.linear_depth(ndc), distance, epsilon = 1e-12);
+        }
+
+        assert_relative_eq!(
+            fov.linear_depth(clip_space.near_depth()),
+            0.25,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            fov.linear_depth(clip_space.far_depth()),
+            300.0,
+            max_relative = 1e-12
+        );
+        let horizon = infinite.linear_depth(clip_space.far_depth());
+        assert!(horizon.is_infinite() && horizon > 0.0);
+    }
+}
+
+fn view_proj(clip_space: ClipSpace) -> Matrix4<f64> {
+    let proj: Matrix4<f64> = PerspectiveFov {
+        fovy: Deg(60.0).into(),
+        aspect: 4.0 / 3.0,
+        near: 1.0,
+        far: 100.0,
+        clip_space: clip_space,
+    }
+    .into();
+    let view = Matrix4::from_translation(Vector3::new(-1.0, -2.0, -3.0));
+    proj * view
+}
+
+fn viewport() -> Aabb2<f64> {
+    Aabb2::new(Point2::new(10.0, 20.0), Point2::new(650.0, 500.0))
+}
+
+#[test]
+fn test_project() {
+    for &clip_space in &clip_spaces() {
+        let m = view_proj(clip_space);
+        let forward: f64 = clip_space.forward();
+        let (near, far): (f64, f64) = if clip_space.reverse_z {
+            (1.0, 0.0)
+        } else {
+            (0.0, 1.0)
+        };
+
+        // points straight ahead of the camera land in the middle of the
+        // viewport, with window depths from 0 to 1
+        let p = project(
+            Point3::new(1.0, 2.0, 3.0 + forward),
+            m,
+            viewport(),
+            clip_space,
+        )
+        .unwrap();
+        assert_relative_eq!(p, Point3::new(330.0, 260.0, near), epsilon = 1e-9);
+        let p = project(
+            Point3::new(1.0, 2.0, 3.0 + 100.0 * forward),
+            m,
+            viewport(),
+            clip_space,
+        )
+        .unwrap();
+        assert_relative_eq!(p, Point3::new(330.0, 260.0, far), epsilon = 1e-9);
+
+        // points on the plane of the camera have no projection
+        assert_eq!(
+            project(Point3::new(5.0, 2.0, 3.0), m, viewport(), clip_space),
+            None
+        );
+    }
+}
+
+#[test]
+fn test_unproject() {
+    for &clip_space in &clip_spaces() {
+        let m = view_proj(clip_space);
+        let forward: f64 = clip_space.forward();
+        let point = Point3::new(-4.0, 5.0, 3.0 + 20.0 * forward);
+        let window = project(point, m, viewport(), clip_space).unwrap();
+        assert_relative_eq!(
+            unproject(window, m, viewport(), clip_space).unwrap(),
+            point,
+            epsilon = 1e-9
+        );
+    }
+
+    assert_eq!(
+        unproject(
+            Point3::new(0.0, 0.0, 0.5),
+            Matrix4::zero(),
+            viewport(),
+            ClipSpace::OPENGL
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_picking_ray() {
+    for &clip_space in &clip_spaces() {
+        let m = view_proj(clip_space);
+        let forward: f64 = clip_space.forward();
+        let point = Point3::new(-4.0, 5.0, 3.0 + 20.0 * forward);
+        let window = project(point, m, viewport(), clip_space).unwrap();
+
+        let ray = picking_ray(Point2::new(window.x, window.y), m, viewport(), clip_space).unwrap();
+        let to_point = point - ray.origin;
+        assert_relative_eq!(ray.direction.magnitude(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(ray.direction, to_point.normalize(), epsilon = 1e-9);
+        // the ray starts on the near plane
+        assert_relative_eq!(
+            (ray.origin - Point3::new(1.0, 2.0, 3.0)).z * forward,
+            1.0,
+            epsilon = 1e-9
+        );
+    }
+
+    // the ray is also defined when the far plane is at infinity
+    let clip_space = ClipSpace::new(DepthRange::ZeroToOne, Handedness::RightHanded, true);
+    let m: Matrix4<f64> = infinite(clip_space).into();
+    let ray = picking_ray(Point2::new(330.0, 260.0), m, viewport(), clip_space).unwrap();
+    assert_relative_eq!(ray.origin, Point3::new(0.0, 0.0, -0.1), epsilon = 1e-12);
+    assert_relative_eq!(ray.direction, -Vector3::unit_z(), epsilon = 1e-12);
+}
+
+#[test]
+fn test_try_into_matrix() {
+    let fov = PerspectiveFov {
+        fovy: Deg(60.0).into(),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+        clip_space: ClipSpace::OPENGL,
+    };
+    assert_eq!(fov.try_into_matrix(), Ok(Matrix4::from(fov)));
+
+    // a window with no height
+    let (width, height) = (0.0f64, 0.0f64);
+    let zero_height = PerspectiveFov {
+        aspect: width / height,
+        ..fov
+    };
+    assert!(match zero_height.try_into_matrix() {
+        Err(ProjectionError::AspectOutOfRange(aspect)) => aspect.is_nan(),
+        _ => false,
+    });
+    assert_eq!(
+        PerspectiveFov {
+            aspect: 1.0 / height,
+            ..fov
+        }
+        .try_into_matrix(),
+        Err(ProjectionError::AspectOutOfRange(1.0 / height))
+    );
+
+    assert_eq!(
+        PerspectiveFov {
+            fovy: Rad(0.0),
+            ..fov
+        }
+        .try_into_matrix(),
+        Err(ProjectionError::FovyOutOfRange(Rad(0.0)))
+    );
+    assert_eq!(
+        PerspectiveFov {
+            fovy: Deg(180.0).into(),
+            ..fov
+        }
+        .try_into_matrix(),
+        Err(ProjectionError::FovyOutOfRange(Deg(180.0).into()))
+    );
+    assert_eq!(
+        PerspectiveFov { near: -0.1, ..fov }.try_into_matrix(),
+        Err(ProjectionError::NearNotPositive(-0.1))
+    );
+    assert_eq!(
+        PerspectiveFov { far: 0.1, ..fov }.try_into_matrix(),
+        Err(ProjectionError::EmptyDepth {
+            near: 0.1,
+            far: 0.1
+        })
+    );
+
+    let infinite = infinite(ClipSpace::OPENGL);
+    assert_eq!(infinite.try_into_matrix(), Ok(Matrix4::from(infinite)));
+    assert_eq!(
+        InfinitePerspectiveFov {
+            near: 0.0,
+            ..infinite
+        }
+        .try_into_matrix(),
+        Err(ProjectionError::NearNotPositive(0.0))
+    );
+
+    let persp = fov.to_perspective();
+    assert_eq!(persp.try_into_matrix(), Ok(Matrix4::from(persp)));
+    assert_eq!(
+        Perspective {
+            right: persp.left,
+            ..persp
+        }
+        .try_into_matrix(),
+        Err(ProjectionError::EmptyWidth {
+            left: persp.left,
+            right: persp.left
+        })
+    );
+    assert_eq!(
+        Perspective {
+            bottom: persp.top,
+            top: persp.bottom,
+            ..persp
+        }
+        .try_into_matrix(),
+        Err(ProjectionError::EmptyHeight {
+            bottom: persp.top,
+            top: persp.bottom
+        })
+    );
+
+    // orthographic projections may be flipped, but not flattened
+    let ortho = Ortho {
+        left: 1.0,
+        right: -1.0,
+        bottom: -1.0,
+        top: 1.0,
+        near: 1.0,
+        far: -1.0,
+        clip_space: ClipSpace::OPENGL,
+    };
+    assert_eq!(ortho.try_into_matrix(), Ok(Matrix4::from(ortho)));
+    assert_eq!(
+        Ortho { top: -1.0, ..ortho }.try_into_matrix(),
+        Err(ProjectionError::EmptyHeight {
+            bottom: -1.0,
+            top: -1.0
+        })
+    );
+    assert_eq!(
+        Ortho { far: 1.0, ..ortho }.try_into_matrix(),
+        Err(ProjectionError::EmptyDepth {
+            near: 1.0,
+            far: 1.0
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "The aspect ratio must be positive and finite")]
+fn test_from_invalid_projection() {
+    let _: Matrix4<f32> = perspective(Deg(60.0), 0.0, 0.1, 100.0);
+}
+
+#[test]
+fn test_from_degenerate_projection() {
+    // `From` only rejects what it always has, leaving the stricter checks to
+    // `try_into_matrix`
+    let _: Matrix4<f64> = ortho(0.0, 800.0, 0.0, 0.0, -1.0, 1.0);
+    assert!(Ortho {
+        left: 0.0,
+        right: 800.0,
+        bottom: 0.0,
+        top: 0.0,
+        near: -1.0,
+        far: 1.0,
+        clip_space: ClipSpace::OPENGL,
+    }
+    .try_into_matrix()
+    .is_err());
+
+    let _: Matrix4<f64> = frustum(-1.0, 1.0, -1.0, 1.0, 0.0, 10.0);
+    assert!(Perspective {
+        left: -1.0,
+        right: 1.0,
+        bottom: -1.0,
+        top: 1.0,
+        near: 0.0,
+        far: 10.0,
+        clip_space: ClipSpace::OPENGL,
+    }
+    .try_into_matrix()
+    .is_err());
+}
+
+#[test]
+fn test_asymmetric_fov() {
+    // a symmetric field of view is the same as `PerspectiveFov`
+    let symmetric = AsymmetricPerspectiveFov {
+        left: Deg(-45.0).into(),
+        right: Deg(45.0).into(),
+        up: Deg(30.0).into(),
+        down: Deg(-30.0).into(),
+        near: 0.1,
+        far: 100.0,
+        clip_space: ClipSpace::OPENGL,
+    };
+    let fovy = PerspectiveFov {
+        fovy: Deg(60.0).into(),
+        aspect: 1.0 / 30.0f64.to_radians().tan(),
+        near: 0.1,
+        far: 100.0,
+        clip_space: ClipSpace::OPENGL,
+    };
+    assert_relative_eq!(
+        Matrix4::from(symmetric),
+        Matrix4::from(fovy),
+        epsilon = 1e-12
+    );
+
+    for &clip_space in &clip_spaces() {
+        let fov = AsymmetricPerspectiveFov::from_tangents_with(
+            -1.0, 0.5, 0.75, -0.25, 0.5, 40.0, clip_space,
+        );
+        assert_eq!(
+            fov,
+            AsymmetricPerspectiveFov {
+                clip_space: clip_space,
+                ..AsymmetricPerspectiveFov::from_tangents(-1.0, 0.5, 0.75, -0.25, 0.5, 40.0)
+            }
+        );
+        let m: Matrix4<f64> = fov.into();
+        assert_eq!(m, Matrix4::from(Perspective::from(fov)));
+
+        // the edges of the field of view project to the edges of the screen
+        let near = to_ndc(m, -0.5, 0.375, 0.5, clip_space);
+        let far = to_ndc(m, 20.0, -10.0, 40.0, clip_space);
+        assert_relative_eq!(
+            near,
+            Point3::new(-1.0, 1.0, clip_space.near_depth()),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            far,
+            Point3::new(1.0, -1.0, clip_space.far_depth()),
+            epsilon = 1e-12
+        );
+
+        assert_relative_eq!(
+            fov.inverse_matrix() * m,
+            Matrix4::identity(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(fov.linear_depth(far.z), 40.0, max_relative = 1e-12);
+    }
+
+    assert_eq!(
+        AsymmetricPerspectiveFov {
+            right: Deg(90.0).into(),
+            ..symmetric
+        }
+        .try_into_matrix(),
+        Err(ProjectionError::AngleOutOfRange(Deg(90.0).into()))
+    );
+    assert_eq!(
+        AsymmetricPerspectiveFov {
+            up: Deg(-30.0).into(),
+            ..symmetric
+        }
+        .try_into_matrix(),
+        Err(ProjectionError::EmptyHeight {
+            bottom: 0.1 * Rad::tan(Deg(-30.0).into()),
+            top: 0.1 * Rad::tan(Deg(-30.0).into())
+        })
+    );
+}
+
+#[test]
+fn test_oblique_near_plane() {
+    for &clip_space in &clip_spaces() {
+        let proj: Matrix4<f64> = Perspective {
+            left: -0.2,
+            right: 0.1,
+            bottom: -0.1,
+            top: 0.15,
+            near: 0.1,
+            far: 100.0,
+            clip_space: clip_space,
+        }
+        .into();
+
+        // a tilted plane two units in front of the camera, facing away
+        let forward: f64 = clip_space.forward();
+        let plane = Plane::from_point_normal(
+            Point3::new(0.0, 0.0, 2.0 * forward),
+            Vector3::new(0.3, -0.2, forward),
+        );
+        let m = oblique_near_plane(proj, plane, clip_space);
+
+        // x and y are unaffected
+        assert_eq!(m.row(0), proj.row(0));
+        assert_eq!(m.row(1), proj.row(1));
+        assert_eq!(m.row(3), proj.row(3));
+
+        for &(x, y) in &[(0.0, 0.0), (1.0, 0.5), (-0.5, 2.0)] {
+            let on_plane = plane.project_point(Point3::new(x, y, 0.0));
+            let ndc = Point3::from_homogeneous(m * on_plane.to_homogeneous());
+            assert_relative_eq!(ndc.z, clip_space.near_depth(), epsilon = 1e-9);
+
+            // points beyond the plane are in front of the new near plane
+            let beyond = on_plane + Vector3::new(0.0, 0.0, forward);
+            let depth = Point3::from_homogeneous(m * beyond.to_homogeneous()).z;
+            let near: f64 = clip_space.near_depth();
+            let far: f64 = clip_space.far_depth();
+            assert!((depth - near) * (far - near) > 0.0);
+        }
+
+        // the plane can also be given as a vector
+        assert_eq!(
+            oblique_near_plane(proj, Vector4::from(plane), clip_space),
+            m
+        );
+    }
+
+    // the result matches Lengyel's original formulation for OpenGL
+    let proj = frustum(-0.1, 0.1, -0.1, 0.1, 0.1, 100.0);
+    let c = Vector4::new(0.3, 0.4, -1.0, -1.0);
+    let q = proj.invert().unwrap() * Vector4::new(1.0, 1.0, 1.0, 1.0);
+    let row = c * (2.0 / c.dot(q)) - proj.row(3);
+    let m = oblique_near_plane(proj, c, ClipSpace::OPENGL);
+    assert_relative_eq!(m.row(2), row, epsilon = 1e-9);
+}
+
+#[test]
+fn test_jitter() {
+    let fov = PerspectiveFov {
+        fovy: Deg(60.0).into(),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+        clip_space: ClipSpace::OPENGL,
+    };
+    let size = Vector2::new(300.0, 200.0);
+    let viewport = Aabb2::new(Point2::new(0.0, 0.0), Point2::new(300.0, 200.0));
+
+    assert_eq!(
+        fov.jittered_matrix(Vector2::zero(), size),
+        Matrix4::from(fov)
+    );
+
+    // the scene is sampled at the offset from the unjittered pixel centres,
+    // so it appears to move by the opposite amount
+    let offset = Vector2::new(0.25, -0.375);
+    let m = fov.jittered_matrix(offset, size);
+    assert_relative_eq!(m, fov.to_perspective().jittered_matrix(offset, size));
+    for &point in &[Point3::new(0.0, 0.0, -1.0), Point3::new(2.0, -3.0, -10.0)] {
+        let before = project(point, fov.into(), viewport, ClipSpace::OPENGL).unwrap();
+        let after = project(point, m, viewport, ClipSpace::OPENGL).unwrap();
+        assert_relative_eq!(after, before - offset.extend(0.0), epsilon = 1e-9);
+    }
+}
+
+#[test]
+fn test_tile() {
+    let persp = Perspective {
+        left: -0.3,
+        right: 0.3,
+        bottom: -0.2,
+        top: 0.2,
+        near: 0.1,
+        far: 100.0,
+        clip_space: ClipSpace::new(DepthRange::ZeroToOne, Handedness::RightHanded, true),
+    };
+    let full: Matrix4<f64> = persp.into();
+    let point = Point3::new(1.0, 0.25, -4.0);
+    let ndc = Point3::from_homogeneous(full * point.to_homogeneous());
+
+    let (columns, rows) = (3, 2);
+    let mut covered = 0;
+    for i in 0..columns {
+        for j in 0..rows {
+            let tile = persp.tile(i, j, columns, rows);
+            assert_relative_eq!(tile.right - tile.left, 0.2, epsilon = 1e-12);
+            assert_relative_eq!(tile.top - tile.bottom, 0.2, epsilon = 1e-12);
+
+            // the tile maps its part of the screen onto the whole of
+            // normalized device coordinates, with unchanged depth
+            let m: Matrix4<f64> = tile.into();
+            let tile_ndc = Point3::from_homogeneous(m * point.to_homogeneous());
+            let center = Point2::new(
+                (i as f64 + 0.5) * 2.0 / columns as f64 - 1.0,
+                (j as f64 + 0.5) * 2.0 / rows as f64 - 1.0,
+            );
+            assert_relative_eq!(
+                tile_ndc,
+                Point3::new(
+                    (ndc.x - center.x) * columns as f64,
+                    (ndc.y - center.y) * rows as f64,
+                    ndc.z
+                ),
+                epsilon = 1e-9
+            );
+            if tile_ndc.x.abs() <= 1.0 && tile_ndc.y.abs() <= 1.0 {
+                covered += 1;
+            }
+        }
+    }
+    assert_eq!(covered, 1);
+
+    let corner = persp.tile(2, 1, columns, rows);
+    assert_relative_eq!(corner.right, persp.right, epsilon = 1e-12);
+    assert_relative_eq!(corner.top, persp.top, epsilon = 1e-12);
+}
+
+#[test]
+#[should_panic(expected = "The tile column must be less than the number of columns")]
+fn test_tile_out_of_range() {
+    let persp = Perspective {
+        left: -1.0f32,
+        right: 1.0,
+        bottom: -1.0,
+        top: 1.0,
+        near: 0.1,
+        far: 100.0,
+        clip_space: ClipSpace::OPENGL,
+    };
+    persp.tile(4, 0, 4, 4);
+}
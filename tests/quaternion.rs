@@ -469,3 +469,327 @@ mod cast {
         );
     }
 }
+
+mod exp_ln {
+    use cgmath::*;
+
+    fn quaternions() -> Vec<Quaternion<f64>> {
+        vec![
+            Quaternion::one(),
+            Quaternion::new(2.0, 0.0, 0.0, 0.0),
+            Quaternion::new(0.5, 1.0, -2.0, 0.25),
+            Quaternion::new(-3.0, 0.5, 0.5, -1.0),
+            Quaternion::new(1.0, 1e-9, -2e-9, 0.0),
+            Quaternion::new(0.0, 0.0, 4.0, 0.0),
+            Quaternion::from_axis_angle(vec3(1.0, 2.0, 3.0).normalize(), Deg(135.0)),
+        ]
+    }
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(Quaternion::<f64>::zero().exp(), Quaternion::one());
+        assert_ulps_eq!(
+            Quaternion::new(1.0f64, 0.0, 0.0, 0.0).exp(),
+            Quaternion::new(1.0f64.exp(), 0.0, 0.0, 0.0)
+        );
+
+        // the exponential of a pure quaternion is a rotation by twice its
+        // magnitude
+        let axis = vec3(2.0f64, -1.0, 2.0) / 3.0;
+        let q = Quaternion::from_sv(0.0, axis * 0.6).exp();
+        assert_ulps_eq!(q, Quaternion::from_axis_angle(axis, Rad(1.2)));
+        assert_ulps_eq!(q.magnitude(), 1.0);
+
+        // tiny angles are handled without losing precision
+        let q = Quaternion::from_sv(0.0, vec3(1e-10f64, 0.0, 0.0)).exp();
+        assert_eq!(q, Quaternion::new(1.0, 1e-10, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_ln() {
+        assert_eq!(Quaternion::<f64>::one().ln(), Quaternion::zero());
+
+        let axis = vec3(2.0f64, -1.0, 2.0) / 3.0;
+        let q = Quaternion::from_axis_angle(axis, Rad(1.2));
+        assert_ulps_eq!(q.ln(), Quaternion::from_sv(0.0, axis * 0.6));
+
+        let ln = Quaternion::new(-2.0f64, 0.0, 0.0, 0.0).ln();
+        assert_ulps_eq!(
+            ln,
+            Quaternion::new(2.0f64.ln(), ::std::f64::consts::PI, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_exp_ln() {
+        for &q in &quaternions() {
+            assert_relative_eq!(q.ln().exp(), q, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_powf() {
+        for &q in &quaternions() {
+            assert_relative_eq!(q.powf(0.0), Quaternion::one(), epsilon = 1e-12);
+            assert_relative_eq!(q.powf(1.0), q, epsilon = 1e-12);
+            assert_relative_eq!(q.powf(2.0), q * q, epsilon = 1e-12);
+            assert_relative_eq!(q.powf(-1.0), q.invert(), epsilon = 1e-12);
+            assert_relative_eq!(q.powf(0.5) * q.powf(0.5), q, epsilon = 1e-12);
+        }
+
+        let q = Quaternion::from_axis_angle(Vector3::unit_z(), Deg(90.0f64));
+        assert_relative_eq!(
+            q.powf(1.0 / 3.0),
+            Quaternion::from_axis_angle(Vector3::unit_z(), Deg(30.0)),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_slerp_powf() {
+        let a = Quaternion::from_axis_angle(vec3(1.0f64, 0.0, 1.0).normalize(), Deg(20.0));
+        let b = Quaternion::from_axis_angle(vec3(0.0f64, 1.0, -1.0).normalize(), Deg(110.0));
+        for &t in &[0.0, 0.25, 0.5, 0.8, 1.0] {
+            assert_relative_eq!(a.slerp(b, t), a * (a.invert() * b).powf(t), epsilon = 1e-12);
+        }
+    }
+}
+
+mod spline {
+    use cgmath::*;
+
+    fn keyframes() -> Vec<Quaternion<f64>> {
+        vec![
+            Quaternion::from_axis_angle(Vector3::unit_z(), Deg(0.0)),
+            Quaternion::from_axis_angle(Vector3::unit_x(), Deg(50.0)),
+            Quaternion::from_axis_angle(vec3(1.0, 1.0, 0.0).normalize(), Deg(120.0)),
+            Quaternion::from_axis_angle(Vector3::unit_y(), Deg(-40.0)),
+            Quaternion::from_axis_angle(vec3(0.0, 1.0, 1.0).normalize(), Deg(75.0)),
+        ]
+    }
+
+    /// The rotation of a few vectors, which is the same for `q` and `-q`.
+    fn rotated(q: Quaternion<f64>) -> [Vector3<f64>; 2] {
+        [
+            q.rotate_vector(Vector3::unit_x()),
+            q.rotate_vector(Vector3::unit_y()),
+        ]
+    }
+
+    type Spline = fn(&[Quaternion<f64>], f64) -> Option<Quaternion<f64>>;
+
+    fn splines() -> [Spline; 2] {
+        [Quaternion::squad_spline, Quaternion::catmull_rom_spline]
+    }
+
+    #[test]
+    fn test_squad() {
+        let a = Quaternion::from_axis_angle(Vector3::unit_x(), Deg(30.0f64));
+        let b = Quaternion::from_axis_angle(Vector3::unit_y(), Deg(60.0f64));
+        let (c, d) = (
+            Quaternion::from_axis_angle(Vector3::unit_z(), Deg(10.0)),
+            Quaternion::from_axis_angle(Vector3::unit_z(), Deg(-20.0)),
+        );
+        assert_relative_eq!(a.squad(c, d, b, 0.0), a, epsilon = 1e-12);
+        assert_relative_eq!(a.squad(c, d, b, 1.0), b, epsilon = 1e-12);
+
+        // with the endpoints as control points, it is the same as `slerp`
+        for &t in &[0.2, 0.5, 0.9] {
+            assert_relative_eq!(a.squad(a, b, b, t), a.slerp(b, t), epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_squad_control_point() {
+        // keyframes evenly spaced around one axis need no correction
+        let q = |angle: f64| Quaternion::from_axis_angle(Vector3::unit_z(), Deg(angle));
+        assert_relative_eq!(
+            q(40.0).squad_control_point(q(10.0), q(70.0)),
+            q(40.0),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_interpolates_keyframes() {
+        let keys = keyframes();
+        for spline in splines().iter() {
+            assert_eq!(spline(&[], 0.5), None);
+            assert_eq!(spline(&keys[..1], 0.5), Some(keys[0]));
+
+            for (i, &key) in keys.iter().enumerate() {
+                let q = spline(&keys, i as f64).unwrap();
+                assert_relative_eq!(q, key, epsilon = 1e-12);
+            }
+            // the time is clamped to the keyframes
+            assert_relative_eq!(spline(&keys, -1.0).unwrap(), keys[0], epsilon = 1e-12);
+            assert_relative_eq!(spline(&keys, 7.5).unwrap(), keys[4], epsilon = 1e-12);
+
+            for i in 0..40 {
+                let q = spline(&keys, i as f64 * 0.1).unwrap();
+                assert_relative_eq!(q.magnitude(), 1.0, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_uniform_rotation() {
+        // evenly spaced keyframes around one axis give a constant angular
+        // velocity between the inner keyframes
+        let keys: Vec<_> = (0..5)
+            .map(|i| Quaternion::from_axis_angle(Vector3::unit_z(), Deg(30.0 * i as f64)))
+            .collect();
+        for spline in splines().iter() {
+            for &time in &[1.25, 1.5, 2.1, 2.75] {
+                assert_relative_eq!(
+                    spline(&keys, time).unwrap(),
+                    Quaternion::from_axis_angle(Vector3::unit_z(), Deg(30.0 * time)),
+                    epsilon = 1e-12
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_smooth_at_keyframes() {
+        // the angular velocity is continuous at the inner keyframes, unlike
+        // piecewise `slerp`
+        let keys = keyframes();
+        let h = 1e-5;
+        for spline in splines().iter() {
+            for i in 1..4 {
+                let key = keys[i];
+                let before = (key.conjugate() * spline(&keys, i as f64 - h).unwrap()).ln() / -h;
+                let after = (key.conjugate() * spline(&keys, i as f64 + h).unwrap()).ln() / h;
+                assert_relative_eq!(before, after, epsilon = 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hemisphere() {
+        // negating keyframes does not change the rotations along the curve
+        let keys = keyframes();
+        let mut flipped = keys.clone();
+        flipped[1] = -flipped[1];
+        flipped[2] = -flipped[2];
+        for spline in splines().iter() {
+            for i in 0..40 {
+                let time = i as f64 * 0.1;
+                let a = rotated(spline(&keys, time).unwrap());
+                let b = rotated(spline(&flipped, time).unwrap());
+                assert_relative_eq!(a[0], b[0], epsilon = 1e-12);
+                assert_relative_eq!(a[1], b[1], epsilon = 1e-12);
+            }
+        }
+    }
+}
+
+mod swing_twist {
+    use cgmath::*;
+
+    fn twist_angle(twist: Quaternion<f64>, axis: Vector3<f64>) -> f64 {
+        2.0 * twist.v.dot(axis).atan2(twist.s)
+    }
+
+    fn swing_angle(swing: Quaternion<f64>) -> f64 {
+        2.0 * swing.v.magnitude().atan2(swing.s.abs())
+    }
+
+    #[test]
+    fn test_swing_twist() {
+        let axis = vec3(1.0, 2.0, 2.0) / 3.0;
+        let swing_axis = axis.cross(Vector3::unit_z()).normalize();
+        let swing = Quaternion::from_axis_angle(swing_axis, Deg(70.0));
+        let twist = Quaternion::from_axis_angle(axis, Deg(-35.0));
+        let q = swing * twist;
+
+        let (s, t) = q.swing_twist(axis);
+        assert_relative_eq!(s * t, q, epsilon = 1e-12);
+        assert_relative_eq!(s, swing, epsilon = 1e-12);
+        assert_relative_eq!(t, twist, epsilon = 1e-12);
+        assert_relative_eq!(s.v.dot(axis), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(t.v.cross(axis), Vector3::zero(), epsilon = 1e-12);
+
+        // the twist angle stays within a half turn either way
+        let (s, t) = (-q).swing_twist(axis);
+        assert_relative_eq!(s * t, -q, epsilon = 1e-12);
+        assert_relative_eq!(twist_angle(t, axis), -35f64.to_radians(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_pure_swing_and_twist() {
+        let axis = Vector3::unit_y();
+        let twist = Quaternion::from_axis_angle(axis, Deg(120.0f64));
+        let (s, t) = twist.swing_twist(axis);
+        assert_relative_eq!(s, Quaternion::one(), epsilon = 1e-12);
+        assert_relative_eq!(t, twist, epsilon = 1e-12);
+
+        let swing = Quaternion::from_axis_angle(Vector3::unit_x(), Deg(45.0f64));
+        let (s, t) = swing.swing_twist(axis);
+        assert_relative_eq!(s, swing, epsilon = 1e-12);
+        assert_relative_eq!(t, Quaternion::one(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_singular() {
+        // a half turn around a perpendicular axis has no defined twist
+        let q = Quaternion::from_axis_angle(Vector3::unit_x(), Deg(180.0f64));
+        let (swing, twist) = q.swing_twist(Vector3::unit_z());
+        assert_eq!(twist, Quaternion::one());
+        assert_eq!(swing, q);
+        assert!(swing.is_finite());
+    }
+
+    #[test]
+    fn test_clamp_twist() {
+        let axis = Vector3::unit_z();
+        let swing = Quaternion::from_axis_angle(Vector3::unit_x(), Deg(30.0f64));
+        let q = swing * Quaternion::from_axis_angle(axis, Deg(80.0));
+
+        let clamped = q.clamp_twist(axis, Deg(-45.0), Deg(45.0));
+        let (s, t) = clamped.swing_twist(axis);
+        assert_relative_eq!(s, swing, epsilon = 1e-12);
+        assert_relative_eq!(twist_angle(t, axis), 45f64.to_radians(), epsilon = 1e-12);
+
+        let clamped = q.clamp_twist(axis, Deg(90.0), Deg(120.0));
+        let (_, t) = clamped.swing_twist(axis);
+        assert_relative_eq!(twist_angle(t, axis), 90f64.to_radians(), epsilon = 1e-12);
+
+        // rotations within the limits are unchanged
+        assert_relative_eq!(
+            q.clamp_twist(axis, Deg(-90.0), Deg(90.0)),
+            q,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_clamp_swing() {
+        let axis = Vector3::unit_z();
+        let twist = Quaternion::from_axis_angle(axis, Deg(60.0f64));
+        let swing_axis = vec3(1.0, 1.0, 0.0).normalize();
+        let q = Quaternion::from_axis_angle(swing_axis, Deg(100.0)) * twist;
+
+        let clamped = q.clamp_swing(axis, Deg(40.0));
+        let (s, t) = clamped.swing_twist(axis);
+        assert_relative_eq!(t, twist, epsilon = 1e-12);
+        assert_relative_eq!(swing_angle(s), 40f64.to_radians(), epsilon = 1e-12);
+        assert_relative_eq!(s.v.normalize(), swing_axis, epsilon = 1e-12);
+
+        // the bone axis ends up on the edge of the cone
+        let bone = clamped.rotate_vector(axis);
+        assert_relative_eq!(bone.angle(axis), Deg(40.0).into(), epsilon = 1e-12);
+
+        assert_eq!(q.clamp_swing(axis, Deg(120.0)), q);
+
+        // the singular case is clamped too
+        let flipped = Quaternion::from_axis_angle(Vector3::unit_x(), Deg(180.0f64));
+        let clamped = flipped.clamp_swing(axis, Deg(30.0));
+        assert_relative_eq!(
+            clamped,
+            Quaternion::from_axis_angle(Vector3::unit_x(), Deg(30.0)),
+            epsilon = 1e-12
+        );
+    }
+}
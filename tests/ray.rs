@@ -0,0 +1,172 @@
+// Copyright 2019 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_at() {
+    let ray = Ray3::new(Point3::new(1.0f64, 2.0, 3.0), Vector3::new(0.0, 0.0, -2.0));
+    assert_eq!(ray.at(0.0), Point3::new(1.0, 2.0, 3.0));
+    assert_eq!(ray.at(1.5), Point3::new(1.0, 2.0, 0.0));
+}
+
+#[test]
+fn test_intersect_plane() {
+    let ray = Ray3::new(Point3::new(0.0f64, 5.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+
+    // the plane y = 1
+    let (t, p) = ray.intersect_plane(Vector3::unit_y(), -1.0).unwrap();
+    assert_ulps_eq!(t, 4.0);
+    assert_ulps_eq!(p, Point3::new(0.0, 1.0, 0.0));
+
+    // the plane y = 6 is behind the ray
+    assert!(ray.intersect_plane(Vector3::unit_y(), -6.0).is_none());
+    // parallel planes are never hit
+    assert!(ray.intersect_plane(Vector3::unit_x(), 0.0).is_none());
+
+    // a short direction is not mistaken for a parallel one
+    let short = Ray3::new(Point3::new(0.0f32, 0.0, 1.0), Vector3::new(0.0, 0.0, -1e-8));
+    let (_, p) = short.intersect_plane(Vector3::unit_z(), 0.0).unwrap();
+    assert_ulps_eq!(p, Point3::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_intersect_sphere() {
+    let ray = Ray3::new(Point3::new(-5.0f64, 0.0, 0.0), Vector3::unit_x());
+    let center = Point3::new(0.0, 0.0, 0.0);
+
+    let (t, p) = ray.intersect_sphere(center, 2.0).unwrap();
+    assert_ulps_eq!(t, 3.0);
+    assert_ulps_eq!(p, Point3::new(-2.0, 0.0, 0.0));
+
+    // from inside the sphere we hit the far side
+    let inside = Ray3::new(center, Vector3::unit_x());
+    let (t, p) = inside.intersect_sphere(center, 2.0).unwrap();
+    assert_ulps_eq!(t, 2.0);
+    assert_ulps_eq!(p, Point3::new(2.0, 0.0, 0.0));
+
+    let miss = Ray3::new(Point3::new(-5.0f64, 3.0, 0.0), Vector3::unit_x());
+    assert!(miss.intersect_sphere(center, 2.0).is_none());
+
+    let away = Ray3::new(Point3::new(-5.0f64, 0.0, 0.0), -Vector3::unit_x());
+    assert!(away.intersect_sphere(center, 2.0).is_none());
+}
+
+#[test]
+fn test_intersect_circle() {
+    let ray = Ray2::new(Point2::new(0.0f64, -4.0), Vector2::new(0.0, 2.0));
+    let (t, p) = ray.intersect_sphere(Point2::new(0.0, 0.0), 1.0).unwrap();
+    assert_ulps_eq!(t, 1.5);
+    assert_ulps_eq!(p, Point2::new(0.0, -1.0));
+}
+
+#[test]
+fn test_intersect_aabb() {
+    let min = Point3::new(-1.0f64, -1.0, -1.0);
+    let max = Point3::new(1.0f64, 1.0, 1.0);
+
+    let ray = Ray3::new(Point3::new(-3.0f64, 0.5, 0.0), Vector3::unit_x());
+    let (t, p) = ray.intersect_aabb(min, max).unwrap();
+    assert_ulps_eq!(t, 2.0);
+    assert_ulps_eq!(p, Point3::new(-1.0, 0.5, 0.0));
+
+    let diagonal = Ray3::new(
+        Point3::new(-2.0f64, -2.0, -2.0),
+        Vector3::new(1.0, 1.0, 1.0),
+    );
+    let (t, _) = diagonal.intersect_aabb(min, max).unwrap();
+    assert_ulps_eq!(t, 1.0);
+
+    let inside = Ray3::new(Point3::new(0.0f64, 0.0, 0.0), Vector3::unit_z());
+    let (t, p) = inside.intersect_aabb(min, max).unwrap();
+    assert_ulps_eq!(t, 1.0);
+    assert_ulps_eq!(p, Point3::new(0.0, 0.0, 1.0));
+
+    let miss = Ray3::new(Point3::new(-3.0f64, 2.0, 0.0), Vector3::unit_x());
+    assert!(miss.intersect_aabb(min, max).is_none());
+
+    let behind = Ray3::new(Point3::new(3.0f64, 0.0, 0.0), Vector3::unit_x());
+    assert!(behind.intersect_aabb(min, max).is_none());
+
+    let ray2 = Ray2::new(Point2::new(0.0f64, 5.0), -Vector2::unit_y());
+    let (t, _) = ray2
+        .intersect_aabb(Point2::new(-1.0, -1.0), Point2::new(1.0, 1.0))
+        .unwrap();
+    assert_ulps_eq!(t, 4.0);
+}
+
+#[test]
+fn test_intersect_triangle() {
+    let a = Point3::new(0.0f64, 0.0, 0.0);
+    let b = Point3::new(1.0f64, 0.0, 0.0);
+    let c = Point3::new(0.0f64, 1.0, 0.0);
+
+    let ray = Ray3::new(Point3::new(0.25f64, 0.25, 2.0), -Vector3::unit_z());
+    let (t, p) = ray.intersect_triangle(a, b, c).unwrap();
+    assert_ulps_eq!(t, 2.0);
+    assert_ulps_eq!(p, Point3::new(0.25, 0.25, 0.0));
+
+    // back faces are hit as well
+    let below = Ray3::new(Point3::new(0.25f64, 0.25, -2.0), Vector3::unit_z());
+    assert!(below.intersect_triangle(a, b, c).is_some());
+
+    let outside = Ray3::new(Point3::new(0.75f64, 0.75, 2.0), -Vector3::unit_z());
+    assert!(outside.intersect_triangle(a, b, c).is_none());
+
+    let parallel = Ray3::new(Point3::new(-1.0f64, 0.25, 0.0), Vector3::unit_x());
+    assert!(parallel.intersect_triangle(a, b, c).is_none());
+}
+
+#[test]
+fn test_intersect_small_triangle() {
+    let a = Point3::new(0.0f32, 0.0, 0.0);
+    let b = Point3::new(1e-4f32, 0.0, 0.0);
+    let c = Point3::new(0.0f32, 1e-4, 0.0);
+
+    let ray = Ray3::new(Point3::new(2.5e-5f32, 2.5e-5, 1.0), -Vector3::unit_z());
+    let (t, _) = ray.intersect_triangle(a, b, c).unwrap();
+    assert_ulps_eq!(t, 1.0);
+
+    let parallel = Ray3::new(Point3::new(-1.0f32, 2.5e-5, 0.0), Vector3::unit_x());
+    assert!(parallel.intersect_triangle(a, b, c).is_none());
+}
+
+#[test]
+fn test_transform() {
+    let ray = Ray3::new(Point3::new(0.0f64, 0.0, 5.0), -Vector3::unit_z());
+    let t = Decomposed {
+        scale: 2.0f64,
+        rot: Quaternion::from_angle_y(Deg(90.0)),
+        disp: Vector3::new(1.0, 0.0, 0.0),
+    };
+
+    let world = ray.transform(&t);
+    assert_relative_eq!(world.origin, Point3::new(11.0, 0.0, 0.0), epsilon = 1e-12);
+    assert_relative_eq!(
+        world.direction,
+        Vector3::new(-2.0, 0.0, 0.0),
+        epsilon = 1e-12
+    );
+
+    // moving the ray back into object space preserves hit distances
+    let local = world.transform(&t.inverse_transform().unwrap());
+    assert_relative_eq!(local, ray, epsilon = 1e-12);
+
+    let m: Matrix4<f64> = t.into();
+    assert_relative_eq!(ray.transform(&m), world, epsilon = 1e-12);
+}
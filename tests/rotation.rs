@@ -45,3 +45,25 @@ fn test_invert_basis3() {
     let a: &Matrix3<_> = a.as_ref();
     assert!(a.is_identity());
 }
+
+#[test]
+fn test_basis3_from_matrix() {
+    let a: Basis3<f64> = rotation::a3();
+    let m: Matrix3<f64> = a.into();
+    assert_ulps_eq!(Basis3::from_matrix(m).unwrap(), a);
+
+    // a rotation that has drifted slightly
+    let drifted = m + Matrix3::new(1e-10, 0.0, -2e-10, 0.0, 3e-10, 0.0, 1e-10, 0.0, 0.0);
+    let repaired: Matrix3<f64> = Basis3::from_matrix(drifted).unwrap().into();
+    assert_relative_eq!(
+        repaired.transpose() * repaired,
+        Matrix3::identity(),
+        epsilon = 1e-14
+    );
+    assert_relative_eq!(repaired, m, epsilon = 1e-9);
+
+    assert!(Basis3::from_matrix(m * 2.0).is_none());
+    assert!(Basis3::from_matrix(m * Matrix3::from_nonuniform_scale(1.0, -1.0)).is_none());
+    assert!(Basis3::from_matrix(-m).is_none());
+    assert!(Basis3::<f64>::from_matrix(Matrix3::zero()).is_none());
+}
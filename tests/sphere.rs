@@ -0,0 +1,180 @@
+// Copyright 2019 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+/// A deterministic, badly distributed cloud of points.
+fn point_cloud() -> Vec<Point3<f64>> {
+    (0..200)
+        .map(|i| {
+            let t = i as f64;
+            Point3::new(
+                (t * 0.37).sin() * 3.0 + 1.0,
+                (t * 0.11).cos() * 1.5 - 2.0,
+                (t * 0.73).sin() * (t * 0.05).cos() * 2.0,
+            )
+        })
+        .collect()
+}
+
+fn assert_encloses(sphere: &Sphere<f64>, points: &[Point3<f64>]) {
+    for &p in points {
+        assert!(sphere.center.distance(p) <= sphere.radius + 1e-9);
+    }
+}
+
+#[test]
+fn test_from_points_small() {
+    assert!(Sphere::<f64>::from_points(&[]).is_none());
+
+    let p = Point3::new(1.0f64, 2.0, 3.0);
+    assert_ulps_eq!(Sphere::from_points(&[p]).unwrap(), Sphere::new(p, 0.0));
+
+    let pair = [Point3::new(-1.0f64, 0.0, 0.0), Point3::new(3.0, 0.0, 0.0)];
+    let sphere = Sphere::from_points(&pair).unwrap();
+    assert_ulps_eq!(sphere, Sphere::new(Point3::new(1.0, 0.0, 0.0), 2.0));
+
+    // the middle point does not touch the minimal sphere
+    let line = [
+        Point3::new(0.0f64, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(4.0, 0.0, 0.0),
+    ];
+    let sphere = Sphere::from_points(&line).unwrap();
+    assert_ulps_eq!(sphere, Sphere::new(Point3::new(2.0, 0.0, 0.0), 2.0));
+}
+
+#[test]
+fn test_from_points_f32_scale() {
+    for &scale in [1e-3f32, 1.0, 1e3].iter() {
+        let triangle = [
+            Point3::new(0.0, 0.0, 0.0) * scale,
+            Point3::new(2.0, 0.0, 0.0) * scale,
+            Point3::new(1.0, 1.0, 0.0) * scale,
+        ];
+        let sphere = Sphere::from_points(&triangle).unwrap();
+        assert_relative_eq!(
+            sphere.center,
+            Point3::new(1.0, 0.0, 0.0) * scale,
+            max_relative = 1e-5
+        );
+        assert_relative_eq!(sphere.radius, scale, max_relative = 1e-5);
+
+        let points = [
+            Point3::new(0.0, 0.0, 0.0) * scale,
+            Point3::new(1.0, 0.0, 0.0) * scale,
+            Point3::new(0.0, 1.0, 0.0) * scale,
+            Point3::new(0.0, 0.0, 1.0) * scale,
+            Point3::new(0.2, 0.3, 0.1) * scale,
+            Point3::new(0.9, 0.9, 0.9) * scale,
+        ];
+        let sphere = Sphere::from_points(&points).unwrap();
+        for &p in points.iter() {
+            assert!(sphere.center.distance(p) <= sphere.radius * (1.0 + 1e-5));
+        }
+    }
+}
+
+#[test]
+fn test_from_points_cube() {
+    let mut corners = Vec::new();
+    for &x in [-1.0f64, 1.0].iter() {
+        for &y in [-1.0f64, 1.0].iter() {
+            for &z in [-1.0f64, 1.0].iter() {
+                corners.push(Point3::new(x, y, z) + Vector3::new(5.0, 0.0, -2.0));
+            }
+        }
+    }
+    corners.push(Point3::new(5.0, 0.0, -2.0));
+
+    let sphere = Sphere::from_points(&corners).unwrap();
+    assert_relative_eq!(sphere.center, Point3::new(5.0, 0.0, -2.0), epsilon = 1e-12);
+    assert_relative_eq!(sphere.radius, 3.0f64.sqrt(), epsilon = 1e-12);
+}
+
+#[test]
+fn test_from_points_is_minimal() {
+    let points = point_cloud();
+    let exact = Sphere::from_points(&points).unwrap();
+    let approx = Sphere::from_points_approx(&points).unwrap();
+    assert_encloses(&exact, &points);
+    assert_encloses(&approx, &points);
+    assert!(exact.radius <= approx.radius + 1e-12);
+
+    // a minimal sphere touches at least two of the points
+    let touching = points
+        .iter()
+        .filter(|&&p| (exact.center.distance(p) - exact.radius).abs() < 1e-9)
+        .count();
+    assert!(touching >= 2);
+
+    // and the order of the points does not matter
+    let mut reversed = points.clone();
+    reversed.reverse();
+    let exact_reversed = Sphere::from_points(&reversed).unwrap();
+    assert_relative_eq!(exact, exact_reversed, epsilon = 1e-9);
+}
+
+#[test]
+fn test_merge() {
+    let a = Sphere::new(Point3::new(0.0f64, 0.0, 0.0), 1.0);
+    let b = Sphere::new(Point3::new(4.0f64, 0.0, 0.0), 1.0);
+    let merged = a.merge(&b);
+    assert_ulps_eq!(merged, Sphere::new(Point3::new(2.0, 0.0, 0.0), 3.0));
+    assert!(merged.contains(&a) && merged.contains(&b));
+
+    let inner = Sphere::new(Point3::new(0.5f64, 0.0, 0.0), 0.25);
+    assert_eq!(a.merge(&inner), a);
+    assert_eq!(inner.merge(&a), a);
+}
+
+#[test]
+fn test_containment() {
+    let sphere = Sphere::new(Point3::new(1.0f64, 1.0, 1.0), 2.0);
+    assert!(sphere.contains_point(Point3::new(1.0, 3.0, 1.0)));
+    assert!(!sphere.contains_point(Point3::new(3.0, 3.0, 1.0)));
+
+    assert!(sphere.intersects(&Sphere::new(Point3::new(1.0, 1.0, 5.0), 2.0)));
+    assert!(!sphere.intersects(&Sphere::new(Point3::new(1.0, 1.0, 5.5), 2.0)));
+    assert!(!sphere.contains(&Sphere::new(Point3::new(1.0, 1.0, 2.0), 1.5)));
+}
+
+#[test]
+fn test_transform() {
+    let sphere = Sphere::new(Point3::new(1.0f64, 0.0, 0.0), 2.0);
+    let t = Decomposed {
+        scale: -3.0f64,
+        rot: Quaternion::from_angle_z(Deg(90.0)),
+        disp: Vector3::new(0.0, 0.0, 1.0),
+    };
+    let transformed = sphere.transform(&t);
+    assert_relative_eq!(
+        transformed.center,
+        Point3::new(0.0, -3.0, 1.0),
+        epsilon = 1e-12
+    );
+    assert_ulps_eq!(transformed.radius, 6.0);
+}
+
+#[test]
+fn test_intersect_ray() {
+    let sphere = Sphere::new(Point3::new(0.0f64, 0.0, -5.0), 1.0);
+    let ray = Ray3::new(Point3::new(0.0f64, 0.0, 0.0), -Vector3::unit_z());
+    let (t, _) = sphere.intersect_ray(&ray).unwrap();
+    assert_ulps_eq!(t, 4.0);
+}
@@ -76,3 +76,286 @@ fn test_serialize() {
 
     assert_ulps_eq!(&t, &deserialized);
 }
+
+#[test]
+fn test_decompose() {
+    let t = Decomposed {
+        scale: 1.5f64,
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), Deg(70.0)),
+        disp: Vector3::new(6.0f64, -7.0, 8.0),
+    };
+    let m: Matrix4<f64> = t.into();
+    let parts = m.decompose().unwrap();
+    assert!(!parts.reflection);
+    assert_relative_eq!(parts.scale, Vector3::new(1.5, 1.5, 1.5), epsilon = 1e-12);
+    assert_relative_eq!(parts.shear, Vector3::zero(), epsilon = 1e-12);
+    assert_relative_eq!(parts.perspective, Vector4::unit_w(), epsilon = 1e-12);
+    assert_relative_eq!(parts.to_decomposed().unwrap(), t, epsilon = 1e-12);
+    assert_relative_eq!(Matrix4::from(parts), m, epsilon = 1e-12);
+}
+
+#[test]
+fn test_decompose_reflection() {
+    let t = Decomposed {
+        scale: -2.0f64,
+        rot: Quaternion::from_angle_y(Deg(30.0)),
+        disp: Vector3::new(1.0f64, 2.0, 3.0),
+    };
+    let m: Matrix4<f64> = t.into();
+    let parts = m.decompose().unwrap();
+    assert!(parts.reflection);
+    assert_relative_eq!(parts.scale, Vector3::new(-2.0, -2.0, -2.0), epsilon = 1e-12);
+    let d = parts.to_decomposed().unwrap();
+    assert_relative_eq!(Matrix4::from(d), m, epsilon = 1e-12);
+
+    // mirroring a single axis is a point reflection followed by a half turn
+    let m = Matrix4::from_nonuniform_scale(-1.0f64, 1.0, 1.0);
+    let parts = m.decompose().unwrap();
+    assert!(parts.reflection);
+    assert_relative_eq!(parts.scale, Vector3::new(-1.0, -1.0, -1.0), epsilon = 1e-12);
+    assert_relative_eq!(Matrix4::from(parts), m, epsilon = 1e-12);
+    let d = parts.to_decomposed().unwrap();
+    assert_relative_eq!(Matrix4::from(d), m, epsilon = 1e-12);
+
+    let m = Matrix4::from_nonuniform_scale(-1.0f64, 2.0, 1.0);
+    let parts = m.decompose().unwrap();
+    assert!(parts.reflection);
+    assert_relative_eq!(Matrix4::from(parts), m, epsilon = 1e-12);
+    assert!(parts.to_decomposed().is_none());
+}
+
+#[test]
+fn test_decompose_shear_and_perspective() {
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    let shear = Matrix4::new(
+        1.0f64, 0.0, 0.0, 0.0,
+        0.5, 1.0, 0.0, 0.0,
+        0.0, 0.25, 1.0, 0.0,
+        0.0, 0.0, 0.0, 1.0,
+    );
+    let m = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+        * Matrix4::from_angle_z(Deg(40.0))
+        * shear
+        * Matrix4::from_nonuniform_scale(2.0, 3.0, 4.0);
+    let parts = m.decompose().unwrap();
+    assert_relative_eq!(parts.scale, Vector3::new(2.0, 3.0, 4.0), epsilon = 1e-12);
+    assert_relative_eq!(parts.shear, Vector3::new(0.5, 0.0, 0.25), epsilon = 1e-12);
+    assert_relative_eq!(
+        parts.rotation,
+        Quaternion::from_angle_z(Deg(40.0)),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        parts.translation,
+        Vector3::new(1.0, 2.0, 3.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(Matrix4::from(parts), m, epsilon = 1e-12);
+    assert!(parts.to_decomposed().is_none());
+
+    let mut projective = m;
+    projective.x.w = 0.1;
+    projective.z.w = -0.2;
+    let parts = projective.decompose().unwrap();
+    assert!(parts.perspective.truncate() != Vector3::zero());
+    assert_relative_eq!(Matrix4::from(parts), projective, epsilon = 1e-12);
+    assert!(parts.to_decomposed().is_none());
+
+    assert!(Matrix4::<f64>::from_scale(0.0).decompose().is_none());
+    let proj: Matrix4<f64> = perspective(Deg(60.0), 1.0, 0.1, 100.0);
+    assert!(proj.decompose().is_none());
+}
+
+fn non_uniform() -> DecomposedNonUniform<Vector3<f64>, Quaternion<f64>> {
+    DecomposedNonUniform {
+        scale: Vector3::new(2.0, 3.0, 0.5),
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), Deg(70.0)),
+        disp: Vector3::new(6.0, -7.0, 8.0),
+    }
+}
+
+#[test]
+fn test_non_uniform_transform() {
+    let t = non_uniform();
+    let m: Matrix4<f64> = t.into();
+    let p = Point3::new(1.0, 2.0, 3.0);
+    let v = Vector3::new(-1.0, 0.5, 2.0);
+    assert_relative_eq!(t.transform_point(p), m.transform_point(p), epsilon = 1e-12);
+    assert_relative_eq!(
+        t.transform_vector(v),
+        m.transform_vector(v),
+        epsilon = 1e-12
+    );
+
+    let vt = t.transform_vector(v);
+    assert_relative_eq!(t.inverse_transform_vector(vt).unwrap(), v, epsilon = 1e-12);
+
+    let one: DecomposedNonUniform<Vector3<f64>, Quaternion<f64>> = Transform::one();
+    assert_relative_eq!(one.transform_point(p), p);
+
+    let parts = m.decompose().unwrap();
+    assert!(parts.to_decomposed().is_none());
+    assert_relative_eq!(
+        parts.to_decomposed_non_uniform().unwrap(),
+        t,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_non_uniform_concat() {
+    let t = non_uniform();
+    let p = Point3::new(1.0, 2.0, 3.0);
+
+    // a uniform scale commutes with any rotation
+    let uniform = DecomposedNonUniform {
+        scale: Vector3::new(1.5, 1.5, 1.5),
+        rot: Quaternion::from_angle_y(Deg(30.0)),
+        disp: Vector3::new(1.0, 2.0, 3.0),
+    };
+    let c = uniform.concat(&t);
+    assert_relative_eq!(
+        c.transform_point(p),
+        uniform.transform_point(t.transform_point(p)),
+        epsilon = 1e-12
+    );
+
+    // a rotation about z commutes with a scale that is the same along x and y
+    let about_z = DecomposedNonUniform {
+        scale: Vector3::new(2.0, 3.0, 0.5),
+        rot: Quaternion::from_angle_z(Deg(30.0)),
+        disp: Vector3::new(1.0, 2.0, 3.0),
+    };
+    let same_xy = DecomposedNonUniform {
+        scale: Vector3::new(1.0, 1.0, 4.0),
+        rot: t.rot,
+        disp: t.disp,
+    };
+    let c = same_xy.concat(&about_z);
+    assert_relative_eq!(
+        c.transform_point(p),
+        same_xy.transform_point(about_z.transform_point(p)),
+        epsilon = 1e-12
+    );
+    let m = Matrix4::from(same_xy) * Matrix4::from(about_z);
+    assert_relative_eq!(Matrix4::from(c), m, epsilon = 1e-12);
+
+    // otherwise the shear is lost, but the origin still ends up in the right
+    // place
+    let c = t.concat(&uniform);
+    assert_relative_eq!(
+        c.transform_point(Point3::origin()),
+        t.transform_point(uniform.transform_point(Point3::origin())),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_non_uniform_inverse() {
+    let p = Point3::new(1.0, 2.0, 3.0);
+
+    let scaled = DecomposedNonUniform {
+        scale: Vector3::new(2.0, 3.0, 0.5),
+        rot: Quaternion::one(),
+        disp: Vector3::new(6.0, -7.0, 8.0),
+    };
+    let inv = scaled.inverse_transform().unwrap();
+    assert_relative_eq!(
+        inv.transform_point(scaled.transform_point(p)),
+        p,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(scaled.concat(&inv), Transform::one(), epsilon = 1e-12);
+
+    let t = non_uniform();
+    let inv = t.inverse_transform().unwrap();
+    assert_relative_eq!(
+        inv.transform_point(Point3::from_vec(t.disp)),
+        Point3::origin(),
+        epsilon = 1e-12
+    );
+
+    let flat = DecomposedNonUniform {
+        scale: Vector3::new(2.0, 0.0, 0.5),
+        rot: Quaternion::one(),
+        disp: Vector3::zero(),
+    };
+    assert!(flat.inverse_transform().is_none());
+    assert!(flat.inverse_transform_vector(Vector3::unit_x()).is_none());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serialize_non_uniform() {
+    let t = non_uniform();
+
+    let serialized = serde_json::to_string(&t).unwrap();
+    let deserialized: DecomposedNonUniform<Vector3<f64>, Quaternion<f64>> =
+        serde_json::from_str(&serialized).unwrap();
+
+    assert_ulps_eq!(&t, &deserialized);
+}
+
+#[test]
+fn test_interpolate() {
+    let a = Decomposed {
+        scale: 1.0f64,
+        rot: Quaternion::from_angle_z(Deg(10.0)),
+        disp: Vector3::new(0.0, 0.0, 0.0),
+    };
+    let b = Decomposed {
+        scale: 3.0f64,
+        rot: Quaternion::from_angle_z(Deg(90.0)),
+        disp: Vector3::new(4.0, -2.0, 8.0),
+    };
+    let expected = Decomposed {
+        scale: 2.5,
+        rot: Quaternion::from_angle_z(Deg(70.0)),
+        disp: Vector3::new(3.0, -1.5, 6.0),
+    };
+    assert_relative_eq!(a.interpolate(&b, 0.75), expected, epsilon = 1e-12);
+    assert_relative_eq!(a.interpolate(&b, 0.0), a, epsilon = 1e-12);
+    assert_relative_eq!(a.interpolate(&b, 1.0), b, epsilon = 1e-12);
+
+    // the negated quaternion is the same rotation, so the path should not
+    // change
+    let flipped = Decomposed { rot: -b.rot, ..b };
+    let result = a.interpolate(&flipped, 0.75);
+    assert_relative_eq!(result.rot.dot(expected.rot).abs(), 1.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_blend() {
+    let t = |angle: f64, x: f64| Decomposed {
+        scale: 1.0 + x,
+        rot: Quaternion::from_angle_y(Deg(angle)),
+        disp: Vector3::new(x, 0.0, 0.0),
+    };
+    let (a, b, c) = (t(0.0, 1.0), t(60.0, 2.0), t(-60.0, 6.0));
+
+    let blended = Decomposed::blend(&[(a, 2.0), (b, 1.0), (c, 1.0)]).unwrap();
+    assert_relative_eq!(blended.scale, 3.5, epsilon = 1e-12);
+    assert_relative_eq!(blended.disp, Vector3::new(2.5, 0.0, 0.0), epsilon = 1e-12);
+    assert_relative_eq!(blended.rot, Quaternion::one(), epsilon = 1e-12);
+
+    // the sign of each quaternion does not matter
+    let flipped = Decomposed { rot: -b.rot, ..b };
+    let again = Decomposed::blend(&[(a, 2.0), (flipped, 1.0), (c, 1.0)]).unwrap();
+    assert_relative_eq!(again, blended, epsilon = 1e-12);
+
+    // two transforms blend like nlerp
+    let two = Decomposed::blend(&[(a, 0.25), (flipped, 0.75)]).unwrap();
+    let nlerp = a.rot.nlerp(b.rot, 0.75);
+    assert_relative_eq!(two.rot.dot(nlerp).abs(), 1.0, epsilon = 1e-12);
+    assert_relative_eq!(two.disp, a.disp.lerp(b.disp, 0.75), epsilon = 1e-12);
+
+    // ties for the largest weight keep the hemisphere of the first one
+    let tied = Decomposed::blend(&[(a, 1.0), (flipped, 1.0)]).unwrap();
+    assert!(tied.rot.dot(a.rot) > 0.0);
+    let tied = Decomposed::blend(&[(flipped, 1.0), (a, 1.0)]).unwrap();
+    assert!(tied.rot.dot(flipped.rot) > 0.0);
+
+    let empty: &[(Decomposed<Vector3<f64>, Quaternion<f64>>, f64)] = &[];
+    assert!(Decomposed::blend(empty).is_none());
+    assert!(Decomposed::blend(&[(a, 1.0), (b, -1.0)]).is_none());
+}